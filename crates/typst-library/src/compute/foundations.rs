@@ -1,5 +1,6 @@
 use typst::eval::{
-    Datetime, Duration, EvalMode, Module, Never, NoneValue, Plugin, Regex, Repr, Version,
+    Datetime, Duration, EvalMode, Module, Never, NoneValue, Plugin, Reflect, Regex, Repr,
+    Version,
 };
 
 use crate::prelude::*;
@@ -28,6 +29,7 @@ pub(super) fn define(global: &mut Scope) {
     global.define_func::<panic>();
     global.define_func::<assert>();
     global.define_func::<eval>();
+    global.define_func::<coalesce>();
 }
 
 /// Returns the string representation of a value.
@@ -231,3 +233,27 @@ pub fn eval(
     }
     typst::eval::eval_string(vm.world(), &text, span, mode, scope)
 }
+
+/// Returns the first of the given values that is not `{none}`.
+///
+/// If all values are `{none}` or no values are given at all, the result is
+/// `{none}`. This is useful to fall back through several optional values, for
+/// example fields that may be missing from data loaded from a file. For just
+/// two values, you can also use the [`{??}` operator]($scripting/#operators).
+///
+/// # Example
+/// ```example
+/// #let data = (title: none, name: "Report")
+/// #coalesce(data.title, data.name, "Untitled")
+/// ```
+#[func]
+pub fn coalesce(
+    /// The values to pick from.
+    #[variadic]
+    values: Vec<Value>,
+) -> Value {
+    values
+        .into_iter()
+        .find(|value| !NoneValue::castable(value))
+        .unwrap_or(Value::None)
+}
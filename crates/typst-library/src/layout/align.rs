@@ -11,7 +11,7 @@ use crate::prelude::*;
 /// Not left nor right, it stands alone \
 /// A work of art, a visual throne
 /// ```
-#[elem(Show)]
+#[elem(scope, Show)]
 pub struct AlignElem {
     /// The [alignment]($alignment) along both axes.
     ///
@@ -30,14 +30,67 @@ pub struct AlignElem {
     #[default]
     pub alignment: Align,
 
+    /// How much to pad the content before aligning it. See the
+    /// [padding's documentation]($pad) for more details.
+    ///
+    /// ```example
+    /// #align(right, pad: (right: 2em))[
+    ///   Berlin, the 22nd of December, 2022
+    /// ]
+    /// ```
+    #[fold]
+    pub pad: Sides<Option<Rel<Length>>>,
+
     /// The content to align.
     #[required]
     pub body: Content,
 }
 
+#[scope]
+impl AlignElem {
+    /// Retrieves the alignment that is active in the given styles.
+    ///
+    /// Both axes are always part of the result. Start and end are resolved to
+    /// left and right according to the current [text direction]($text.dir).
+    /// Ratios are returned as given, measured from the start.
+    ///
+    /// ```example
+    /// #set text(lang: "ar")
+    /// #style(styles => align.current(styles))
+    /// ```
+    #[func]
+    pub fn current(
+        /// The styles to retrieve the alignment from, as received by the
+        /// [`style`]($style) function.
+        styles: Styles,
+    ) -> Align {
+        let styles = StyleChain::new(&styles);
+        let align = Self::alignment_in(styles);
+        let x = match align.x() {
+            // Ratios are already relative to the start and stay as given.
+            Some(HAlign::Ratio(ratio)) => HAlign::Ratio(ratio),
+            _ => match align.resolve(styles).x {
+                FixedAlign::Start => HAlign::Left,
+                FixedAlign::End => HAlign::Right,
+                _ => HAlign::Center,
+            },
+        };
+
+        // Vertical alignments don't depend on the text direction.
+        let y = align.y().unwrap_or_default();
+        x + y
+    }
+}
+
 impl Show for AlignElem {
     #[tracing::instrument(name = "AlignElem::show", skip_all)]
     fn show(&self, _: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
-        Ok(self.body().styled(Self::set_alignment(self.alignment(styles))))
+        let mut body = self.body();
+        let pad = self.pad(styles);
+        if pad.iter().any(|side| side.is_some_and(|v| !v.is_zero())) {
+            body = body.padded(pad.map(Option::unwrap_or_default));
+        }
+
+        Ok(body.styled(Self::set_alignment(self.alignment(styles))))
     }
 }
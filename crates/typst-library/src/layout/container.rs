@@ -1,7 +1,7 @@
 use typst::eval::AutoValue;
 
 use super::VElem;
-use crate::layout::Spacing;
+use crate::layout::{AlignElem, Spacing};
 use crate::prelude::*;
 
 /// An inline-level container that sizes content.
@@ -36,6 +36,18 @@ pub struct BoxElem {
     /// ```
     pub width: Sizing,
 
+    /// The maximum width of a fractionally sized box.
+    ///
+    /// A box with a fractional width stops growing at this width. The rest
+    /// of its share of the line stays empty, right after the box. This has no
+    /// effect on boxes with other widths.
+    ///
+    /// ```example
+    /// A #box(width: 1fr, max-width: 2cm, repeat[.]) B
+    /// ```
+    #[resolve]
+    pub max_width: Option<Length>,
+
     /// The height of the box.
     pub height: Smart<Rel<Length>>,
 
@@ -319,6 +331,19 @@ pub struct BlockElem {
     #[default(VElem::block_spacing(Em::new(1.2).into()))]
     pub below: VElem,
 
+    /// How to align the block's content within it. When `{none}`, the content
+    /// is aligned as outside of the block.
+    ///
+    /// ```example
+    /// #block(
+    ///   width: 100%,
+    ///   inset: 8pt,
+    ///   fill: silver,
+    ///   align: right,
+    /// )[Aligned to the right]
+    /// ```
+    pub align: Option<Align>,
+
     /// Whether to clip the content inside the block.
     #[default(false)]
     pub clip: bool,
@@ -343,8 +368,12 @@ impl Layout for BlockElem {
         styles: StyleChain,
         regions: Regions,
     ) -> SourceResult<Fragment> {
-        // Apply inset.
+        // Apply alignment and inset.
         let mut body = self.body(styles).unwrap_or_default();
+        if let Some(align) = self.align(styles) {
+            body = body.styled(AlignElem::set_alignment(align));
+        }
+
         let inset = self.inset(styles);
         if inset.iter().any(|v| !v.is_zero()) {
             body = body.clone().padded(inset.map(|side| side.map(Length::from)));
@@ -1,3 +1,4 @@
+use super::BoxElem;
 use crate::prelude::*;
 
 /// Hides content without affecting layout.
@@ -17,11 +18,32 @@ pub struct HideElem {
     /// The content to hide.
     #[required]
     pub body: Content,
+
+    /// Whether the hidden content still takes up its space.
+    ///
+    /// When `{false}`, the hidden content takes up no space at all, as if it
+    /// was placed in a box of zero size. It is still laid out, so labels and
+    /// counter updates within it keep their effect.
+    ///
+    /// ```example
+    /// Hello #hide(reserve: false)[Jane] Joe
+    /// ```
+    #[default(true)]
+    pub reserve: bool,
 }
 
 impl Show for HideElem {
     #[tracing::instrument(name = "HideElem::show", skip(self))]
-    fn show(&self, _: &mut Vt, _: StyleChain) -> SourceResult<Content> {
-        Ok(self.body().styled(MetaElem::set_data(vec![Meta::Hide])))
+    fn show(&self, _: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
+        let body = self.body().styled(MetaElem::set_data(vec![Meta::Hide]));
+        if self.reserve(styles) {
+            return Ok(body);
+        }
+
+        Ok(BoxElem::new()
+            .with_body(Some(body))
+            .with_width(Abs::zero().into())
+            .with_height(Smart::Custom(Abs::zero().into()))
+            .pack())
     }
 }
@@ -397,13 +397,9 @@ impl PageElem {
 
         // Construct the numbering (for header or footer).
         let numbering_marginal = numbering.clone().map(|numbering| {
-            let both = match &numbering {
-                Numbering::Pattern(pattern) => pattern.pieces() >= 2,
-                Numbering::Func(_) => true,
-            };
-
+            let both = numbering.displays_total();
             let mut counter =
-                Counter::new(CounterKey::Page).display(Some(numbering), both);
+                Counter::new(CounterKey::Page).display(Smart::Custom(numbering), both);
 
             // We interpret the Y alignment as selecting header or footer
             // and then ignore it for aligning the actual number.
@@ -1496,11 +1496,15 @@ fn commit(
             Item::Fractional(v, elem) => {
                 let amount = v.share(fr, remaining);
                 if let Some((elem, styles)) = elem {
-                    let region = Size::new(amount, full);
+                    // A capped box leaves the rest of its share empty.
+                    let max = elem.max_width(*styles).unwrap_or(Abs::inf());
+                    let region = Size::new(amount.min(max), full);
                     let pod = Regions::one(region, Axes::new(true, false));
                     let mut frame = elem.layout(vt, *styles, pod)?.into_frame();
                     frame.translate(Point::with_y(TextElem::baseline_in(*styles)));
+                    let rest = amount - frame.width();
                     push(&mut offset, frame);
+                    offset += rest.max(Abs::zero());
                 } else {
                     offset += amount;
                 }
@@ -1,3 +1,5 @@
+use typst::util::hash128;
+
 use crate::prelude::*;
 
 use super::AlignElem;
@@ -28,6 +30,86 @@ pub struct RepeatElem {
     /// The content to repeat.
     #[required]
     pub body: Content,
+
+    /// The minimum space between the instances of the body.
+    ///
+    /// Only as many instances as fit with this gap between them are shown.
+    /// This keeps the dots leading up to the page numbers in an outline from
+    /// merging into a solid line.
+    ///
+    /// ```example
+    /// #outline(fill: repeat(gap: 4pt)[.])
+    ///
+    /// = Introduction
+    /// ```
+    #[resolve]
+    pub gap: Length,
+
+    /// Whether to snap the instances of the body to a grid.
+    ///
+    /// When enabled, the instances are placed at multiples of their width
+    /// from the left edge of the page instead of being spread out over the
+    /// available space. This way, the instances of repeats on consecutive
+    /// lines line up, like the dots leading up to the page numbers in an
+    /// outline.
+    ///
+    /// ```example
+    /// #set page(width: 150pt)
+    /// #outline(fill: repeat(snap: true)[.])
+    ///
+    /// = Introduction
+    /// = A longer heading
+    /// ```
+    #[default(false)]
+    pub snap: bool,
+
+    /// Whether to spread the instances of the body out over the available
+    /// space.
+    ///
+    /// When enabled, the space that is left over is distributed evenly
+    /// between the instances, so that the first and last one are flush with
+    /// the edges. Otherwise, the instances are placed right next to each other
+    /// and aligned according to the current [alignment]($align). This has no
+    /// effect if the instances are snapped.
+    ///
+    /// ```example
+    /// #box(width: 1fr, repeat[-- ])
+    /// #box(width: 1fr, repeat(justify: false)[-- ])
+    /// ```
+    #[default(true)]
+    pub justify: bool,
+
+    /// Whether to only show complete instances of the body.
+    ///
+    /// By default, the repeat stops before an instance that doesn't fit
+    /// anymore, which may leave a gap at its edge. When disabled, the
+    /// instances run from edge to edge instead and the one that doesn't fit
+    /// is clipped. Where it is cut off depends on the current
+    /// [alignment]($align), so that with the default alignment, the clipped
+    /// instance is at the end of the line. Instances that are cut off are
+    /// never spread out.
+    ///
+    /// ```example
+    /// #box(width: 1fr, repeat(justify: false)[-- ])
+    /// #box(width: 1fr, repeat(complete: false)[-- ])
+    /// ```
+    #[default(true)]
+    pub complete: bool,
+
+    /// The space to keep free after the last instance of the body.
+    ///
+    /// The instances are only laid out in the space before this gap. When
+    /// used as the fill of an [outline]($outline), the gap replaces the word
+    /// space in front of the page number, so that the distance between the
+    /// leader and the page number is the same for every entry.
+    ///
+    /// ```example
+    /// #outline(fill: repeat(trailing: 6pt)[.])
+    ///
+    /// = Introduction
+    /// ```
+    #[resolve]
+    pub trailing: Option<Length>,
 }
 
 impl Layout for RepeatElem {
@@ -42,11 +124,39 @@ impl Layout for RepeatElem {
         let piece = self.body().layout(vt, styles, pod)?.into_frame();
         let align = AlignElem::alignment_in(styles).resolve(styles);
 
-        let fill = regions.size.x;
+        let trailing = self.trailing(styles).unwrap_or_default();
+        let fill = (regions.size.x - trailing).max(Abs::zero());
         let width = piece.width();
-        let count = (fill / width).floor();
-        let remaining = fill % width;
-        let apart = remaining / (count - 1.0);
+        let gap = self.gap(styles);
+        let step = width + gap;
+        let snap = self.snap(styles) && width > Abs::zero();
+
+        // When snapping, the first instance starts at the next multiple of
+        // its width and the gap from the left edge of the page. The position
+        // is known from the previous layout iteration, so only repeats that
+        // snap are located. Before the first iteration, the introspector
+        // reports the top-left of the first page.
+        let mut phase = Abs::zero();
+        let mut located = None;
+        if snap {
+            let location = match self.0.location() {
+                Some(location) => location,
+                None => vt.locator.locate(hash128(&self.0)),
+            };
+            let x = vt.introspector.position(location).point.x;
+            phase = (step - x % step) % step;
+            let mut elem = self.0.clone();
+            elem.set_location(location);
+            located = Some(elem);
+        }
+
+        // Without complete instances, the one that doesn't fit is clipped.
+        let complete = self.complete(styles);
+        let fit = (fill - phase + gap) / step;
+        let count = if complete { fit.floor() } else { fit.ceil() };
+        let remaining = fill - phase - count * width - (count - 1.0).max(0.0) * gap;
+        let justify = self.justify(styles) && !snap && complete;
+        let apart = gap + if justify { remaining / (count - 1.0) } else { Abs::zero() };
 
         let size = Size::new(regions.size.x, piece.height());
 
@@ -59,16 +169,28 @@ impl Layout for RepeatElem {
             frame.set_baseline(piece.baseline());
         }
 
-        let mut offset = Abs::zero();
-        if count == 1.0 {
+        let mut offset = phase;
+        if (count == 1.0 || !justify) && !snap {
             offset += align.x.position(remaining);
         }
 
         if width > Abs::zero() {
+            let mut instances = Frame::soft(Size::new(fill, piece.height()));
             for _ in 0..(count as usize).min(1000) {
-                frame.push_frame(Point::with_x(offset), piece.clone());
-                offset += piece.width() + apart;
+                instances.push_frame(Point::with_x(offset), piece.clone());
+                offset += width + apart;
+            }
+
+            if !complete {
+                instances.clip(Path::rect(instances.size()));
             }
+
+            frame.push_frame(Point::zero(), instances);
+        }
+
+        // Record the position of the repeat for the next layout iteration.
+        if let Some(elem) = located {
+            frame.meta_iter([Meta::Elem(elem)]);
         }
 
         Ok(Fragment::frame(frame))
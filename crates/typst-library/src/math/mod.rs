@@ -251,7 +251,7 @@ impl Layout for EquationElem {
             if let Some(numbering) = self.numbering(styles) {
                 let pod = Regions::one(regions.base(), Axes::splat(false));
                 let counter = Counter::of(Self::elem())
-                    .display(Some(numbering), false)
+                    .display(Smart::Custom(numbering), false)
                     .layout(vt, styles, pod)?
                     .into_frame();
 
@@ -365,9 +365,19 @@ impl Refable for EquationElem {
 
 impl Outlinable for EquationElem {
     fn outline(&self, vt: &mut Vt) -> SourceResult<Option<Content>> {
-        let Some(numbering) = self.numbering(StyleChain::default()) else {
+        let numbering = self.numbering(StyleChain::default());
+        self.outline_numbered(vt, numbering.as_ref())
+    }
+
+    fn outline_numbered(
+        &self,
+        vt: &mut Vt,
+        numbering: Option<&Numbering>,
+    ) -> SourceResult<Option<Content>> {
+        // Only numbered equations are outlined.
+        if self.numbering(StyleChain::default()).is_none() {
             return Ok(None);
-        };
+        }
 
         // After synthesis, this should always be custom content.
         let mut supplement = match self.supplement(StyleChain::default()) {
@@ -375,6 +385,18 @@ impl Outlinable for EquationElem {
             _ => Content::empty(),
         };
 
+        // Equations have no title, but labelled ones are described by their
+        // label.
+        let description = self
+            .0
+            .label()
+            .map(|label| TextElem::packed(eco_format!(": {}", label.0)))
+            .unwrap_or_default();
+
+        let Some(numbering) = numbering else {
+            return Ok(Some(supplement + description));
+        };
+
         if !supplement.is_empty() {
             supplement += TextElem::packed("\u{a0}");
         }
@@ -382,9 +404,13 @@ impl Outlinable for EquationElem {
         let numbers = self
             .counter()
             .at(vt, self.0.location().unwrap())?
-            .display(vt, &numbering)?;
+            .display(vt, numbering)?;
+
+        Ok(Some(supplement + numbers + description))
+    }
 
-        Ok(Some(supplement + numbers))
+    fn participates_in_hierarchy(&self) -> bool {
+        false
     }
 }
 
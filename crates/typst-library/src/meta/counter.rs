@@ -5,7 +5,7 @@ use smallvec::{smallvec, SmallVec};
 use typst::eval::{Repr, Tracer};
 use typst::model::DelayedErrors;
 
-use super::{FigureElem, HeadingElem, Numbering, NumberingPattern};
+use super::{page_numbering_at, FigureElem, HeadingElem, Numbering, NumberingPattern};
 use crate::layout::PageElem;
 use crate::math::EquationElem;
 use crate::prelude::*;
@@ -344,13 +344,22 @@ impl Counter {
         /// to display the counter. If given a function, that function receives
         /// each number of the counter as a separate argument. If the amount of
         /// numbers varies, e.g. for the heading argument, you can use an
-        /// [argument sink]($arguments).
+        /// [argument sink]($arguments). If the function has a named `counter`
+        /// parameter, it additionally receives the counter that is displayed.
+        /// This way, one numbering function can number different kinds of
+        /// elements differently.
         ///
-        /// If this is omitted, displays the counter with the numbering style
-        /// for the counted element or with the pattern `{"1.1"}` if no such
-        /// style exists.
+        /// If this is omitted or `{auto}`, the numbering is picked from the
+        /// context in which the counter is displayed:
+        /// - For the page counter, this is the numbering of the page the
+        ///   counter is displayed on, or the pattern `{"1"}` if that page isn't
+        ///   numbered.
+        /// - For headings, figures, and equations, this is the numbering set
+        ///   for them where the counter is displayed.
+        /// - For all other counters and if no such numbering is set, this is
+        ///   the pattern `{"1.1"}`.
         #[default]
-        numbering: Option<Numbering>,
+        numbering: Smart<Numbering>,
         /// If enabled, displays the current and final top-level count together.
         /// Both can be styled through a single numbering pattern. This is used
         /// by the page numbering property to display the current and total
@@ -397,18 +406,44 @@ impl Counter {
         UpdateElem::new(self.0, update).pack()
     }
 
-    /// Gets the value of the counter at the given location. Always returns an
-    /// array of integers, even if the counter has just one number.
-    #[func]
-    pub fn at(
+    /// Gets the value of the counter at the given location or element. Always
+    /// returns an array of integers, even if the counter has just one number.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.")
+    ///
+    /// = Introduction
+    /// = Background <background>
+    /// = Analysis
+    ///
+    /// #locate(loc => counter(heading).at(<background>))
+    /// ```
+    #[func(name = "at")]
+    pub fn at_target(
         &self,
         /// The virtual typesetter.
         vt: &mut Vt,
-        /// The location at which the counter value should be retrieved. A
-        /// suitable location can be retrieved from [`locate`]($locate) or
-        /// [`query`]($query).
-        location: Location,
+        /// The callsite span.
+        span: Span,
+        /// Where the counter value should be retrieved. This can be a location,
+        /// as retrieved from [`locate`]($locate) or [`query`]($query), or a
+        /// label or selector that matches exactly one element.
+        target: LocatableSelector,
     ) -> SourceResult<CounterState> {
+        let location = match target.0 {
+            Selector::Location(location) => location,
+            selector => match vt.introspector.query(&selector).as_slice() {
+                [elem] => elem.location().unwrap(),
+                [] => bail!(span, "selector does not match any element"),
+                _ => bail!(span, "selector matches multiple elements"),
+            },
+        };
+
+        self.at(vt, location)
+    }
+
+    /// Gets the value of the counter at the given location.
+    pub fn at(&self, vt: &mut Vt, location: Location) -> SourceResult<CounterState> {
         let sequence = self.sequence(vt)?;
         let offset = vt
             .introspector
@@ -602,7 +637,7 @@ struct DisplayElem {
 
     /// The numbering to display the counter with.
     #[required]
-    numbering: Option<Numbering>,
+    numbering: Smart<Numbering>,
 
     /// Whether to display both the current and final value.
     #[required]
@@ -617,9 +652,12 @@ impl Show for DisplayElem {
             let counter = self.counter();
             let numbering = self
                 .numbering()
+                .as_custom()
                 .or_else(|| {
-                    let CounterKey::Selector(Selector::Elem(func, _)) = counter.0 else {
-                        return None;
+                    let func = match counter.0 {
+                        CounterKey::Page => return Some(page_numbering_at(vt, location)),
+                        CounterKey::Selector(Selector::Elem(func, _)) => func,
+                        _ => return None,
                     };
 
                     if func == HeadingElem::elem() {
@@ -640,7 +678,7 @@ impl Show for DisplayElem {
                 counter.at(vt, location)?
             };
 
-            state.display(vt, &numbering)
+            state.display(vt, &numbering.for_counter(&counter))
         }))
     }
 }
@@ -1,10 +1,11 @@
 use std::str::FromStr;
 
 use super::{
-    Count, Counter, CounterKey, CounterUpdate, LocalName, Numbering, NumberingPattern,
+    Count, Counter, CounterKey, CounterState, CounterUpdate, HeadingElem, LocalName,
+    Numbering, NumberingPattern,
 };
 use crate::layout::{BlockElem, PlaceElem, VElem};
-use crate::meta::{Outlinable, Refable, Supplement};
+use crate::meta::{Outlinable, OutlineParts, Refable, Supplement};
 use crate::prelude::*;
 use crate::text::TextElem;
 use crate::visualize::ImageElem;
@@ -123,6 +124,22 @@ pub struct FigureElem {
     /// The figure's caption.
     pub caption: Option<FigureCaption>,
 
+    /// A shorter caption to show in the [outline]($outline) instead of the
+    /// figure's caption.
+    ///
+    /// The figure is still only outlined if it has a caption.
+    ///
+    /// ```example
+    /// #outline(title: [Figures], target: figure)
+    ///
+    /// #figure(
+    ///   rect[Hello],
+    ///   caption: [A rectangle, which has four sides and four corners.],
+    ///   outline-caption: [A rectangle],
+    /// )
+    /// ```
+    pub outline_caption: Option<Content>,
+
     /// The kind of figure this is.
     ///
     /// If set to `{auto}`, the figure will try to automatically determine its
@@ -176,6 +193,28 @@ pub struct FigureElem {
     #[default(Some(NumberingPattern::from_str("1").unwrap().into()))]
     pub numbering: Option<Numbering>,
 
+    /// The heading level whose numbers precede the figure's own number.
+    ///
+    /// When set, the figure displays the numbers of the enclosing heading up
+    /// to this level, followed by its position among the figures of the same
+    /// kind since that heading. The count thus restarts with each such
+    /// heading. Captions, references and outlines all show the same number.
+    /// Use a numbering pattern with one part per level, like `{"1.1"}`.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.")
+    /// #set figure(numbering: "1.1", chapter-level: 1)
+    /// #outline(target: figure)
+    ///
+    /// = Introduction
+    /// #figure(rect(), caption: [A box])
+    ///
+    /// = Results
+    /// #figure(circle(), caption: [A disc])
+    /// #figure(square(), caption: [A square])
+    /// ```
+    pub chapter_level: Option<NonZeroUsize>,
+
     /// The vertical gap between the body and caption.
     #[default(Em::new(0.65).into())]
     pub gap: Length,
@@ -195,6 +234,11 @@ pub struct FigureElem {
     /// number or reset the counter.
     #[synthesized]
     pub counter: Option<Counter>,
+
+    /// The figure's chapter-relative number, if it has a chapter level.
+    #[internal]
+    #[synthesized]
+    pub chapter_numbers: Option<CounterState>,
 }
 
 #[scope]
@@ -263,6 +307,14 @@ impl Synthesize for FigureElem {
             }),
         )));
 
+        // Number the figure relative to its chapter, if requested.
+        let chapter_numbers = match (self.chapter_level(styles), self.0.location()) {
+            (Some(level), Some(location)) if numbering.is_some() => {
+                Some(self.chapter_numbers_at(vt, &kind, level, location)?)
+            }
+            _ => None,
+        };
+
         // Fill the figure's caption.
         let mut caption = self.caption(styles);
         if let Some(caption) = &mut caption {
@@ -275,16 +327,65 @@ impl Synthesize for FigureElem {
 
         self.push_placement(self.placement(styles));
         self.push_caption(caption);
+        self.push_outline_caption(self.outline_caption(styles));
         self.push_kind(Smart::Custom(kind));
         self.push_supplement(Smart::Custom(supplement.map(Supplement::Content)));
         self.push_numbering(numbering);
         self.push_outlined(self.outlined(styles));
         self.push_counter(Some(counter));
+        self.push_chapter_numbers(chapter_numbers);
 
         Ok(())
     }
 }
 
+impl FigureElem {
+    /// Determine the figure's numbers within the chapter it is located in.
+    fn chapter_numbers_at(
+        &self,
+        vt: &mut Vt,
+        kind: &FigureKind,
+        level: NonZeroUsize,
+        location: Location,
+    ) -> SourceResult<CounterState> {
+        // The chapter starts at the last numbered heading that is at most as
+        // deep as the chapter level.
+        let headings = Selector::Elem(HeadingElem::elem(), None);
+        let chapter = vt
+            .introspector
+            .query(&headings.before(location.into(), false))
+            .into_iter()
+            .rev()
+            .find(|heading| {
+                let heading = heading.to::<HeadingElem>().unwrap();
+                heading.numbering(StyleChain::default()).is_some()
+                    && heading.level(StyleChain::default()) <= level
+            });
+
+        // Count the numbered figures of the same kind up to this one.
+        let mut figures =
+            Selector::Elem(Self::elem(), Some(dict! { "kind" => kind.clone() }))
+                .before(location.into(), true);
+        if let Some(chapter) = &chapter {
+            figures = figures.after(chapter.location().unwrap().into(), false);
+        }
+        let count = vt
+            .introspector
+            .query(&figures)
+            .iter()
+            .filter(|figure| {
+                let figure = figure.to::<Self>().unwrap();
+                figure.numbering(StyleChain::default()).is_some()
+            })
+            .count();
+
+        let mut numbers = Counter::of(HeadingElem::elem()).at(vt, location)?.0;
+        numbers.resize(level.get(), 0);
+        numbers.push(count);
+        Ok(CounterState(numbers))
+    }
+}
+
 impl Show for FigureElem {
     #[tracing::instrument(name = "FigureElem::show", skip_all)]
     fn show(&self, _: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
@@ -329,6 +430,11 @@ impl Count for FigureElem {
     fn update(&self) -> Option<CounterUpdate> {
         // If the figure is numbered, step the counter by one.
         // This steps the `counter(figure)` which is global to all numbered figures.
+        // Chapter-relative figures instead set it to their precomputed numbers.
+        if let Some(numbers) = self.chapter_numbers() {
+            return Some(CounterUpdate::Set(numbers));
+        }
+
         self.numbering(StyleChain::default())
             .is_some()
             .then(|| CounterUpdate::Step(NonZeroUsize::ONE))
@@ -355,6 +461,23 @@ impl Refable for FigureElem {
 
 impl Outlinable for FigureElem {
     fn outline(&self, vt: &mut Vt) -> SourceResult<Option<Content>> {
+        let numbering = self.numbering(StyleChain::default());
+        self.outline_numbered(vt, numbering.as_ref())
+    }
+
+    fn outline_numbered(
+        &self,
+        vt: &mut Vt,
+        numbering: Option<&Numbering>,
+    ) -> SourceResult<Option<Content>> {
+        Ok(self.outline_parts(vt, numbering)?.map(OutlineParts::join))
+    }
+
+    fn outline_parts(
+        &self,
+        vt: &mut Vt,
+        numbering: Option<&Numbering>,
+    ) -> SourceResult<Option<OutlineParts>> {
         if !self.outlined(StyleChain::default()) {
             return Ok(None);
         }
@@ -363,29 +486,48 @@ impl Outlinable for FigureElem {
             return Ok(None);
         };
 
-        let mut realized = caption.body();
+        let body = self
+            .outline_caption(StyleChain::default())
+            .unwrap_or_else(|| caption.body());
+
+        // Unnumbered figures don't step their counter, so they stay unnumbered.
+        let numbering =
+            numbering.filter(|_| self.numbering(StyleChain::default()).is_some());
+
         if let (
             Smart::Custom(Some(Supplement::Content(mut supplement))),
             Some(counter),
             Some(numbering),
-        ) = (
-            self.supplement(StyleChain::default()),
-            self.counter(),
-            self.numbering(StyleChain::default()),
-        ) {
+        ) = (self.supplement(StyleChain::default()), self.counter(), numbering)
+        {
             let location = self.0.location().unwrap();
-            let numbers = counter.at(vt, location)?.display(vt, &numbering)?;
+            let numbers = counter.at(vt, location)?.display(vt, numbering)?;
 
             if !supplement.is_empty() {
                 supplement += TextElem::packed('\u{a0}');
             }
 
             let separator = caption.separator(StyleChain::default());
-
-            realized = supplement + numbers + separator + caption.body();
+            return Ok(Some(OutlineParts::new(
+                Some(supplement + numbers),
+                separator,
+                body,
+            )));
         }
 
-        Ok(Some(realized))
+        Ok(Some(OutlineParts::unnumbered(body)))
+    }
+
+    fn group(&self) -> Option<(Value, Content)> {
+        // After synthesis, the kind is always custom.
+        let Smart::Custom(kind) = self.kind(StyleChain::default()) else {
+            return None;
+        };
+        Some((kind.into_value(), Refable::supplement(self)))
+    }
+
+    fn participates_in_hierarchy(&self) -> bool {
+        false
     }
 }
 
@@ -1,7 +1,9 @@
 use typst::font::FontWeight;
 use typst::util::option_eq;
 
-use super::{Counter, CounterUpdate, LocalName, Numbering, Outlinable, Refable};
+use super::{
+    Counter, CounterUpdate, LocalName, Numbering, Outlinable, OutlineParts, Refable,
+};
 use crate::layout::{BlockElem, HElem, VElem};
 use crate::meta::{Count, Supplement};
 use crate::prelude::*;
@@ -54,6 +56,23 @@ pub struct HeadingElem {
     /// == A subsection
     /// === A sub-subsection
     /// ```
+    ///
+    /// To number each level differently, pass an array of numberings. The
+    /// first one displays the heading's top-level number, the second one the
+    /// number at the second level, and so on, while the last one is used for
+    /// all deeper levels. Each numbering only receives the number at its own
+    /// level, so separators belong into the patterns' prefixes. The outline
+    /// displays the same numbers, regardless of its `depth`: Hiding deeper
+    /// headings doesn't shorten the numbers of the shown ones.
+    ///
+    /// ```example
+    /// #let words = ("One", "Two", "Three")
+    /// #set heading(numbering: (n => words.at(n - 1), ".1"))
+    ///
+    /// = A section
+    /// == A subsection
+    /// === A sub-subsection
+    /// ```
     pub numbering: Option<Numbering>,
 
     /// A supplement for the heading.
@@ -115,6 +134,23 @@ pub struct HeadingElem {
     #[default(Smart::Auto)]
     pub bookmarked: Smart<bool>,
 
+    /// Content to show in front of the heading's number in the
+    /// [outline]($outline), like "Chapter".
+    ///
+    /// When set to `{auto}`, numbered headings are prefixed with their
+    /// [supplement]($heading.supplement), just like in references to
+    /// them. This doesn't affect the heading itself.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.", supplement: [Chapter])
+    /// #show heading.where(level: 1): set heading(outline-prefix: auto)
+    /// #outline()
+    ///
+    /// = Introduction
+    /// == Scope
+    /// ```
+    pub outline_prefix: Option<Smart<Content>>,
+
     /// The heading's title.
     #[required]
     pub body: Content,
@@ -134,6 +170,7 @@ impl Synthesize for HeadingElem {
         self.push_supplement(Smart::Custom(Some(Supplement::Content(supplement))));
         self.push_outlined(self.outlined(styles));
         self.push_bookmarked(self.bookmarked(styles));
+        self.push_outline_prefix(self.outline_prefix(styles));
 
         Ok(())
     }
@@ -145,7 +182,7 @@ impl Show for HeadingElem {
         let mut realized = self.body();
         if let Some(numbering) = self.numbering(styles) {
             realized = Counter::of(Self::elem())
-                .display(Some(numbering), false)
+                .display(Smart::Custom(numbering), false)
                 .spanned(self.span())
                 + HElem::new(Em::new(0.3).into()).with_weak(true).pack()
                 + realized;
@@ -210,19 +247,53 @@ impl Refable for HeadingElem {
 
 impl Outlinable for HeadingElem {
     fn outline(&self, vt: &mut Vt) -> SourceResult<Option<Content>> {
+        let numbering = self.numbering(StyleChain::default());
+        self.outline_numbered(vt, numbering.as_ref())
+    }
+
+    fn outline_numbered(
+        &self,
+        vt: &mut Vt,
+        numbering: Option<&Numbering>,
+    ) -> SourceResult<Option<Content>> {
+        Ok(self.outline_parts(vt, numbering)?.map(OutlineParts::join))
+    }
+
+    fn outline_parts(
+        &self,
+        vt: &mut Vt,
+        numbering: Option<&Numbering>,
+    ) -> SourceResult<Option<OutlineParts>> {
         if !self.outlined(StyleChain::default()) {
             return Ok(None);
         }
 
-        let mut content = self.body();
-        if let Some(numbering) = self.numbering(StyleChain::default()) {
-            let numbers = Counter::of(Self::elem())
-                .at(vt, self.0.location().unwrap())?
-                .display(vt, &numbering)?;
-            content = numbers + SpaceElem::new().pack() + content;
+        // Unnumbered headings don't step the counter, so they stay unnumbered.
+        let body = self.body();
+        let Some(numbering) =
+            numbering.filter(|_| self.numbering(StyleChain::default()).is_some())
+        else {
+            return Ok(Some(OutlineParts::unnumbered(body)));
         };
 
-        Ok(Some(content))
+        let numbers = Counter::of(Self::elem())
+            .at(vt, self.0.location().unwrap())?
+            .display(vt, numbering)?;
+
+        Ok(Some(OutlineParts::new(Some(numbers), SpaceElem::new().pack(), body)))
+    }
+
+    fn prefix(&self, _: &mut Vt) -> SourceResult<Option<Content>> {
+        Ok(match self.outline_prefix(StyleChain::default()) {
+            Some(Smart::Auto) => {
+                let supplement = Refable::supplement(self);
+                (self.numbering(StyleChain::default()).is_some()
+                    && !supplement.is_empty())
+                .then_some(supplement)
+            }
+            Some(Smart::Custom(prefix)) => Some(prefix),
+            None => None,
+        })
     }
 
     fn level(&self) -> NonZeroUsize {
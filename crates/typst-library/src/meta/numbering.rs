@@ -1,17 +1,19 @@
 use std::str::FromStr;
 
 use chinese_number::{ChineseCase, ChineseCountMethod, ChineseVariant, NumberToChinese};
-use ecow::EcoVec;
+use ecow::{eco_vec, EcoVec};
+use typst::eval::Arg;
 use typst::export::{PdfPageLabel, PdfPageLabelStyle};
 
+use super::Counter;
 use crate::prelude::*;
 use crate::text::Case;
 
 /// Applies a numbering to a sequence of numbers.
 ///
 /// A numbering defines how a sequence of numbers should be displayed as
-/// content. It is defined either through a pattern string or an arbitrary
-/// function.
+/// content. It is defined either through a pattern string, an arbitrary
+/// function, or an array of numberings for the individual levels.
 ///
 /// A numbering pattern consists of counting symbols, for which the actual
 /// number is substituted, their prefixes, and one suffix. The prefixes and the
@@ -40,6 +42,10 @@ pub fn numbering(
     /// `ㄱ`, and `*`. They are replaced by the number in the sequence, in the
     /// given case.
     ///
+    /// Zeros right in front of a `1` pad the number with leading zeros. For
+    /// example, `001` displays the number 7 as "007" and the number 1234 as
+    /// "1234".
+    ///
     /// The `*` character means that symbols should be used to count, in the
     /// order of `*`, `†`, `‡`, `§`, `¶`, and `‖`. If there are more than six
     /// items, the number is represented using multiple symbols.
@@ -57,6 +63,11 @@ pub fn numbering(
     /// particularly useful in itself, it means that you can just give arbitrary
     /// numberings to the `numbering` function without caring whether they are
     /// defined as a pattern or function.
+    ///
+    /// Finally, this parameter can be an array of numberings. Then, each number
+    /// is displayed by the numbering at its position in the array (or the last
+    /// one if there are more numbers than numberings) and the results are
+    /// joined.
     numbering: Numbering,
     /// The numbers to apply the numbering to. Must be positive.
     ///
@@ -75,6 +86,9 @@ pub enum Numbering {
     Pattern(NumberingPattern),
     /// A closure mapping from an item's number to content.
     Func(Func),
+    /// Numberings indexed by level, where each one displays only the number
+    /// at its level and the last one is used for all deeper levels.
+    Array(Vec<Numbering>),
 }
 
 impl Numbering {
@@ -86,6 +100,13 @@ impl Numbering {
                 let args = Args::new(func.span(), numbers.iter().copied());
                 func.call_vm(vm, args)?
             }
+            Self::Array(levels) => {
+                let mut parts = vec![];
+                for (i, &number) in numbers.iter().enumerate() {
+                    parts.push(level_at(levels, i).apply_vm(vm, &[number])?);
+                }
+                join_levels(parts)
+            }
         })
     }
 
@@ -94,9 +115,27 @@ impl Numbering {
         Ok(match self {
             Self::Pattern(pattern) => Value::Str(pattern.apply(numbers).into()),
             Self::Func(func) => func.call_vt(vt, numbers.iter().copied())?,
+            Self::Array(levels) => {
+                let mut parts = vec![];
+                for (i, &number) in numbers.iter().enumerate() {
+                    parts.push(level_at(levels, i).apply_vt(vt, &[number])?);
+                }
+                join_levels(parts)
+            }
         })
     }
 
+    /// Whether this numbering is passed the total number of pages along with
+    /// the current one when numbering pages. This is the case for a numbering
+    /// function and for patterns or arrays with at least two levels.
+    pub fn displays_total(&self) -> bool {
+        match self {
+            Self::Pattern(pattern) => pattern.pieces() >= 2,
+            Self::Func(_) => true,
+            Self::Array(levels) => levels.len() >= 2,
+        }
+    }
+
     /// Create a new `PdfNumbering` from a `Numbering` applied to a page
     /// number.
     pub fn apply_pdf(&self, number: usize) -> Option<PdfPageLabel> {
@@ -104,14 +143,14 @@ impl Numbering {
             return None;
         };
 
-        let Some((prefix, kind, case)) = pat.pieces.first() else {
+        let Some((prefix, kind, case, width)) = pat.pieces.first() else {
             return None;
         };
 
-        // If there is a suffix, we cannot use the common style optimisation,
-        // since PDF does not provide a suffix field.
+        // If there is a suffix or padding, we cannot use the common style
+        // optimisation, since PDF does not provide fields for them.
         let mut style = None;
-        if pat.suffix.is_empty() {
+        if pat.suffix.is_empty() && *width == 1 {
             use NumberingKind as Kind;
             use PdfPageLabelStyle as Style;
             match (kind, case) {
@@ -141,6 +180,26 @@ impl Numbering {
         Some(PdfPageLabel { prefix, style, offset })
     }
 
+    /// Pass the counter to a numbering function that has a named `counter`
+    /// parameter.
+    pub fn for_counter(self, counter: &Counter) -> Self {
+        match self {
+            Self::Func(func) if func.has_named_param("counter") => {
+                let span = func.span();
+                let arg = Arg {
+                    span,
+                    name: Some("counter".into()),
+                    value: Spanned::new(counter.clone().into_value(), span),
+                };
+                Self::Func(func.with(Args { span, items: eco_vec![arg] }))
+            }
+            Self::Array(levels) => Self::Array(
+                levels.into_iter().map(|level| level.for_counter(counter)).collect(),
+            ),
+            other => other,
+        }
+    }
+
     /// Trim the prefix suffix if this is a pattern.
     pub fn trimmed(mut self) -> Self {
         if let Self::Pattern(pattern) = &mut self {
@@ -150,6 +209,36 @@ impl Numbering {
     }
 }
 
+/// The numbering for the level with the given index, falling back to the last
+/// one for deeper levels.
+fn level_at(levels: &[Numbering], index: usize) -> &Numbering {
+    levels.get(index).or(levels.last()).unwrap()
+}
+
+/// Join the displayed numbers of all levels, keeping a string if all of them
+/// are strings.
+fn join_levels(parts: Vec<Value>) -> Value {
+    let mut joined = Value::Str("".into());
+    for part in parts {
+        joined = match (joined, part) {
+            (Value::Str(a), Value::Str(b)) => Value::Str(a + b),
+            (a, b) => Value::Content(a.display() + b.display()),
+        };
+    }
+    joined
+}
+
+/// The numbering of the page the given location is on.
+///
+/// Falls back to the pattern `"1"` if the page isn't numbered.
+pub fn page_numbering_at(vt: &Vt, location: Location) -> Numbering {
+    vt.introspector
+        .page_numbering(location)
+        .cast::<Option<Numbering>>()
+        .unwrap()
+        .unwrap_or_else(|| Numbering::Pattern(NumberingPattern::from_str("1").unwrap()))
+}
+
 impl From<NumberingPattern> for Numbering {
     fn from(pattern: NumberingPattern) -> Self {
         Self::Pattern(pattern)
@@ -161,9 +250,16 @@ cast! {
     self => match self {
         Self::Pattern(pattern) => pattern.into_value(),
         Self::Func(func) => func.into_value(),
+        Self::Array(levels) => levels.into_value(),
     },
     v: NumberingPattern => Self::Pattern(v),
     v: Func => Self::Func(v),
+    array: Array => {
+        if array.is_empty() {
+            bail!("array must contain at least one numbering");
+        }
+        Self::Array(array.into_iter().map(Value::cast).collect::<StrResult<_>>()?)
+    },
 }
 
 /// How to turn a number into text.
@@ -171,13 +267,18 @@ cast! {
 /// A pattern consists of a prefix, followed by one of `1`, `a`, `A`, `i`,
 /// `I`, `い`, `イ`, `א`, `가`, `ㄱ`, or `*`, and then a suffix.
 ///
+/// Zeros right in front of a `1` pad the number with leading zeros, such that
+/// it has at least as many digits as the zeros and the `1` together.
+///
 /// Examples of valid patterns:
 /// - `1)`
 /// - `a.`
 /// - `(I)`
+/// - `001`
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct NumberingPattern {
-    pub pieces: EcoVec<(EcoString, NumberingKind, Case)>,
+    /// The counting symbols with their prefix, case, and minimum width.
+    pub pieces: EcoVec<(EcoString, NumberingKind, Case, usize)>,
     pub suffix: EcoString,
     trimmed: bool,
 }
@@ -188,16 +289,16 @@ impl NumberingPattern {
         let mut fmt = EcoString::new();
         let mut numbers = numbers.iter();
 
-        for (i, ((prefix, kind, case), &n)) in
+        for (i, ((prefix, kind, case, width), &n)) in
             self.pieces.iter().zip(&mut numbers).enumerate()
         {
             if i > 0 || !self.trimmed {
                 fmt.push_str(prefix);
             }
-            fmt.push_str(&kind.apply(n, *case));
+            fmt.push_str(&kind.apply_padded(n, *case, *width));
         }
 
-        for ((prefix, kind, case), &n) in
+        for ((prefix, kind, case, width), &n) in
             self.pieces.last().into_iter().cycle().zip(numbers)
         {
             if prefix.is_empty() {
@@ -205,7 +306,7 @@ impl NumberingPattern {
             } else {
                 fmt.push_str(prefix);
             }
-            fmt.push_str(&kind.apply(n, *case));
+            fmt.push_str(&kind.apply_padded(n, *case, *width));
         }
 
         if !self.trimmed {
@@ -218,16 +319,16 @@ impl NumberingPattern {
     /// Apply only the k-th segment of the pattern to a number.
     pub fn apply_kth(&self, k: usize, number: usize) -> EcoString {
         let mut fmt = EcoString::new();
-        if let Some((prefix, _, _)) = self.pieces.first() {
+        if let Some((prefix, ..)) = self.pieces.first() {
             fmt.push_str(prefix);
         }
-        if let Some((_, kind, case)) = self
+        if let Some((_, kind, case, width)) = self
             .pieces
             .iter()
             .chain(self.pieces.last().into_iter().cycle())
             .nth(k)
         {
-            fmt.push_str(&kind.apply(number, *case));
+            fmt.push_str(&kind.apply_padded(number, *case, *width));
         }
         fmt.push_str(&self.suffix);
         fmt
@@ -251,10 +352,18 @@ impl FromStr for NumberingPattern {
                 continue;
             };
 
-            let prefix = pattern[handled..i].into();
+            // Zeros in front of an arabic counting symbol are padding.
+            let mut prefix = &pattern[handled..i];
+            let mut width = 1;
+            if kind == NumberingKind::Arabic {
+                let unpadded = prefix.trim_end_matches('0');
+                width += prefix.len() - unpadded.len();
+                prefix = unpadded;
+            }
+
             let case =
                 if c.is_uppercase() || c == '壹' { Case::Upper } else { Case::Lower };
-            pieces.push((prefix, kind, case));
+            pieces.push((prefix.into(), kind, case, width));
             handled = c.len_utf8() + i;
         }
 
@@ -271,8 +380,11 @@ cast! {
     NumberingPattern,
     self => {
         let mut pat = EcoString::new();
-        for (prefix, kind, case) in &self.pieces {
+        for (prefix, kind, case, width) in &self.pieces {
             pat.push_str(prefix);
+            for _ in 1..*width {
+                pat.push('0');
+            }
             let mut c = kind.to_char();
             if *case == Case::Upper {
                 c = c.to_ascii_uppercase();
@@ -342,6 +454,12 @@ impl NumberingKind {
         }
     }
 
+    /// Apply the numbering to the given number and pad it with leading zeros
+    /// to the given width.
+    pub fn apply_padded(self, n: usize, case: Case, width: usize) -> EcoString {
+        eco_format!("{:0>width$}", self.apply(n, case))
+    }
+
     /// Apply the numbering to the given number.
     pub fn apply(self, mut n: usize, case: Case) -> EcoString {
         match self {
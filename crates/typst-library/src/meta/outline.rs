@@ -1,13 +1,24 @@
-use std::str::FromStr;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::Arc;
 
+use comemo::Prehashed;
+use ecow::EcoVec;
+use smallvec::smallvec;
+use typst::diag::warning;
 use typst::util::option_eq;
 
 use super::{
-    Counter, CounterKey, HeadingElem, LocalName, Numbering, NumberingPattern, Refable,
+    page_numbering_at, Counter, CounterKey, CounterState, FigureElem, HeadingElem,
+    LocalName, Numbering, Refable,
+};
+use crate::layout::{
+    BlockElem, BoxElem, ColumnsElem, HElem, HideElem, ParElem, ParbreakElem, RepeatElem,
+    Spacing, TableElem, VElem,
 };
-use crate::layout::{BoxElem, HElem, HideElem, ParbreakElem, RepeatElem, Spacing};
 use crate::prelude::*;
 use crate::text::{LinebreakElem, SpaceElem, TextElem};
+use crate::visualize::ImageElem;
 
 /// A table of contents, figures, or other elements.
 ///
@@ -52,21 +63,91 @@ use crate::text::{LinebreakElem, SpaceElem, TextElem};
 /// `title` and `indent` parameters. If desired, however, it is possible to have
 /// more control over the outline's look and style through the
 /// [`outline.entry`]($outline.entry) element.
-#[elem(scope, keywords = ["Table of Contents"], Show, Finalize, LocalName)]
+#[elem(scope, keywords = ["Table of Contents"], Locatable, Show, Finalize, LocalName)]
 pub struct OutlineElem {
     /// The title of the outline.
     ///
     /// - When set to `{auto}`, an appropriate title for the
-    ///   [text language]($text.lang) will be used. This is the default.
+    ///   [text language]($text.lang) will be used. This is the default. For
+    ///   outlines of image or table figures, this is a title like "List of
+    ///   Figures".
     /// - When set to `{none}`, the outline will not have a title.
     /// - A custom title can be set by passing content.
+    /// - A function is called with a dictionary describing the outline and
+    ///   should return the title. The dictionary contains the number of
+    ///   `entries` and the `page` the outline starts on, as its page counter
+    ///   shows it.
     ///
     /// The outline's heading will not be numbered by default, but you can
     /// force it to be with a show-set rule:
     /// `{show outline: set heading(numbering: "1.")}`
     /// ```
     #[default(Some(Smart::Auto))]
-    pub title: Option<Smart<Content>>,
+    pub title: Option<Smart<OutlineTitle>>,
+
+    /// How to display the outline's title.
+    ///
+    /// - `{"heading"}`: As a top-level [heading]($heading). This is the
+    ///   default.
+    /// - `{"content"}`: As a plain block with the title's content. It is then
+    ///   not part of the heading hierarchy, so it appears neither in other
+    ///   outlines nor in the exported PDF's bookmarks.
+    ///
+    /// ```example
+    /// #outline(title: strong[Contents], title-as: "content")
+    ///
+    /// = Introduction
+    /// = Background
+    /// ```
+    #[default(OutlineTitleAs::Heading)]
+    pub title_as: OutlineTitleAs,
+
+    /// Whether the outline's title should appear as a bookmark in the exported
+    /// PDF's outline.
+    ///
+    /// The title heading is never listed in an outline, including other
+    /// outlines in the same document, and by default, it is not bookmarked
+    /// either. This has no effect if the title is displayed as content.
+    ///
+    /// ```example
+    /// #outline(title-bookmarked: true)
+    ///
+    /// = Introduction
+    /// ```
+    #[default(false)]
+    pub title_bookmarked: bool,
+
+    /// The spacing between the outline's title and its first entry.
+    ///
+    /// When this is `{none}`, the spacing below the title is determined by the
+    /// spacing around headings. Otherwise, the given spacing replaces it, so it
+    /// is not affected by rules that style the document's other headings.
+    ///
+    /// ```example
+    /// #outline(title-gap: 2em)
+    ///
+    /// = Introduction
+    /// = Background
+    /// ```
+    pub title_gap: Option<Spacing>,
+
+    /// Whether to hide the outline, including its title, if it has no
+    /// entries.
+    ///
+    /// This is useful in templates, where the document may or may not contain
+    /// any headings or other elements matching the outline's `target`.
+    ///
+    /// ```example
+    /// #outline(
+    ///   title: [Figures],
+    ///   target: figure,
+    ///   hide-empty: true,
+    /// )
+    ///
+    /// = Introduction
+    /// ```
+    #[default(false)]
+    pub hide_empty: bool,
 
     /// The type of element to include in the outline.
     ///
@@ -88,12 +169,84 @@ pub struct OutlineElem {
     ///   caption: [Experiment results],
     /// )
     /// ```
+    ///
+    /// To list different kinds of elements in one outline, you can combine
+    /// their selectors with [`or`]($selector.or). The entries are then listed
+    /// in document order. Note that all entries share one hierarchy: Since
+    /// figures always have level one, a figure ends the nesting of the headings
+    /// before it.
+    ///
+    /// ```example
+    /// #outline(
+    ///   title: [Contents & Figures],
+    ///   target: selector(heading).or(figure),
+    /// )
+    ///
+    /// = Introduction
+    /// #figure(
+    ///   rect[Hello],
+    ///   caption: [A rectangle],
+    /// )
+    /// ```
     #[default(LocatableSelector(Selector::Elem(
         HeadingElem::elem(),
         Some(dict! { "outlined" => true })
     )))]
     pub target: LocatableSelector,
 
+    /// Whether to only list the elements in the section the outline is
+    /// located in.
+    ///
+    /// The section starts at the last outlined heading before the outline and
+    /// ends right before the next one of the same or a higher level. This way,
+    /// an outline placed right after a chapter's heading lists just that
+    /// chapter's subsections. An outline before the first heading lists the
+    /// elements of the whole document.
+    ///
+    /// ```example
+    /// = Introduction
+    /// #outline(title: none, local: true)
+    /// == Motivation
+    /// == Scope
+    ///
+    /// = Background
+    /// == History
+    /// ```
+    #[default(false)]
+    pub local: bool,
+
+    /// A function that decides which of the targeted elements are included in
+    /// the outline.
+    ///
+    /// The function receives each element matched by the
+    /// [`target`]($outline.target) and must return a boolean. Elements for
+    /// which it returns `{false}` are left out of the outline completely and
+    /// don't take part in the nesting of the remaining entries.
+    ///
+    /// The elements are already laid out at this point, so the function can
+    /// also decide based on their [location]($content.location). For example,
+    /// `{it => it.location().page() > 10}` only keeps the elements after the
+    /// first ten pages.
+    ///
+    /// ```example
+    /// #outline(
+    ///   title: [List of Figures],
+    ///   target: figure,
+    ///   filter: it => it.at("label", default: none) != <skip>,
+    /// )
+    ///
+    /// #figure(
+    ///   rect(height: 8pt),
+    ///   caption: [A rectangle],
+    /// )
+    ///
+    /// #figure(
+    ///   circle(radius: 4pt),
+    ///   caption: [A circle],
+    /// ) <skip>
+    /// ```
+    pub filter: Option<Func>,
+
     /// The maximum level up to which elements are included in the outline. When
     /// this argument is `{none}`, all elements are included.
     ///
@@ -110,7 +263,122 @@ pub struct OutlineElem {
     /// === Nope
     /// Not included.
     /// ```
-    pub depth: Option<NonZeroUsize>,
+    ///
+    /// Instead of just a maximum level, this can also be an array of a minimum
+    /// and a maximum level, both inclusive. For instance, `{(2, 4)}` includes
+    /// levels two through four. If the maximum is `{none}`, there is no upper
+    /// bound. The minimum combines with the [`start`]($outline.start) level,
+    /// whichever of them is higher applies.
+    ///
+    /// ```example
+    /// #outline(depth: (2, 3))
+    ///
+    /// = Nope
+    /// == Yes
+    /// === Still
+    /// ==== Nope
+    /// ```
+    pub depth: Option<OutlineDepth>,
+
+    /// The minimum level from which on elements are included in the outline.
+    ///
+    /// Elements with a lower level are skipped and are not considered as
+    /// parents of the included entries. Together with `depth`, this restricts
+    /// the outline to a window of levels, which must not be empty.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.")
+    /// #outline(start: 2)
+    ///
+    /// = Nope
+    /// Not included.
+    ///
+    /// == Yes
+    /// Subsection.
+    ///
+    /// === Also
+    /// Subsubsection.
+    /// ```
+    #[default(NonZeroUsize::ONE)]
+    pub start: NonZeroUsize,
+
+    /// The last page from which elements are included in the outline.
+    ///
+    /// An element's page is determined by the value of the page counter at
+    /// its location, so it matches the displayed page number rather than the
+    /// physical page. Elements on this very page are still included. When
+    /// `{none}`, elements on all pages are included.
+    ///
+    /// ```example
+    /// #set page(height: 80pt)
+    /// #outline(max-page: 1)
+    ///
+    /// = Included
+    /// #pagebreak()
+    /// = Excluded
+    /// ```
+    pub max_page: Option<NonZeroUsize>,
+
+    /// Whether to list the entries in reverse document order.
+    ///
+    /// The nesting of the entries is still determined in document order, so
+    /// nested entries keep their indentation and are listed above the entry
+    /// they are nested in.
+    ///
+    /// ```example
+    /// #outline(
+    ///   title: [Changes],
+    ///   reverse: true,
+    /// )
+    ///
+    /// = Version 1.0
+    /// = Version 1.1
+    /// = Version 2.0
+    /// ```
+    #[default(false)]
+    pub reverse: bool,
+
+    /// An element under which all other entries are nested, like the title
+    /// of a book.
+    ///
+    /// - `{none}`: There is no root. This is the default.
+    /// - `{auto}`: The first outlined top-level heading is the root.
+    /// - A [label]($label): The outlined element with this label is the root.
+    ///
+    /// The root entry itself isn't indented, while all other entries are
+    /// indented by one more level than without a root.
+    ///
+    /// ```example
+    /// #outline(root: auto)
+    ///
+    /// = My Book
+    /// = Introduction
+    /// == Scope
+    /// = Conclusion
+    /// ```
+    pub root: Option<Smart<Label>>,
+
+    /// How to sort the entries.
+    ///
+    /// When `{none}`, the entries are listed in document order. Otherwise,
+    /// this function receives each outlined element and returns the key to
+    /// sort the entries by, like a string. Entries with equal keys stay in
+    /// document order.
+    ///
+    /// Sorted entries are not nested in each other, so they are neither
+    /// indented nor do they display page ranges.
+    ///
+    /// ```example
+    /// #outline(
+    ///   title: [Figures],
+    ///   target: figure,
+    ///   sort: it => it.caption.body.text,
+    /// )
+    ///
+    /// #figure(rect(), caption: [Zebra])
+    /// #figure(circle(), caption: [Apple])
+    /// ```
+    pub sort: Option<Func>,
 
     /// How to indent the outline's entries.
     ///
@@ -128,11 +396,13 @@ pub struct OutlineElem {
     ///   (starting at 0 for top-level headings/elements) and can return a
     ///   relative length or content making up the indent. For example,
     ///   `{n => n * 2em}` would be equivalent to just specifying `{2em}`, while
-    ///   `{n => [→ ] * n}` would indent with one arrow per nesting level.
+    ///   `{n => [→ ] * n}` would indent with one arrow per nesting level. If
+    ///   the function accepts a second parameter, it also receives the element
+    ///   of the entry, as in `{(n, it) => ...}`.
     ///
     /// *Migration hints:*  Specifying `{true}` (equivalent to `{auto}`) or
-    /// `{false}` (equivalent to `{none}`) for this option is deprecated and
-    /// will be removed in a future release.
+    /// `{false}` (equivalent to `{none}`) for this option is deprecated, emits
+    /// a warning, and will be removed in a future release.
     ///
     /// ```example
     /// #set heading(numbering: "1.a.")
@@ -161,83 +431,1101 @@ pub struct OutlineElem {
     /// #lorem(10)
     /// ```
     #[default(None)]
+    #[parse({
+        // A fractional indent would be repeated for each level, which has no
+        // sensible layout.
+        let option: Option<Spanned<Value>> = args.named("indent")?;
+        if let Some(Spanned { v: Value::Fraction(_), span }) = &option {
+            bail!(
+                error!(*span, "outline indent cannot be fractional")
+                    .with_hint("use a length like `2em` instead")
+            );
+        }
+        let option = option
+            .map(|Spanned { v, span }| {
+                v.cast::<Option<Smart<OutlineIndent>>>()
+                    .at(span)
+                    .map(|v| Spanned::new(v, span))
+            })
+            .transpose()?;
+        if let Some(Spanned {
+            v: Some(Smart::Custom(OutlineIndent::Bool(v))),
+            span,
+        }) = &option
+        {
+            let replacement = if *v { "auto" } else { "none" };
+            vm.vt.tracer.warn(
+                warning!(*span, "boolean values for `indent` are deprecated")
+                    .with_hint(eco_format!("use `{replacement}` instead")),
+            );
+        }
+        option.map(|s| s.v)
+    })]
     pub indent: Option<Smart<OutlineIndent>>,
 
-    /// Content to fill the space between the title and the page number. Can be
-    /// set to `none` to disable filling.
-    ///
-    /// ```example
-    /// #outline(fill: line(length: 100%))
-    ///
-    /// = A New Beginning
-    /// ```
-    #[default(Some(RepeatElem::new(TextElem::packed(".")).pack()))]
-    pub fill: Option<Content>,
-}
+    /// The maximum indent of an entry when `indent` is a length.
+    ///
+    /// A length indent grows with each nesting level, so deeply nested
+    /// entries can run off narrow pages. When set, the indent is clamped to
+    /// this length. Only the absolute part of a relative indent is clamped.
+    ///
+    /// ```example
+    /// #outline(indent: 2em, max-indent: 3em)
+    ///
+    /// = One
+    /// == Two
+    /// === Three
+    /// ==== Four
+    /// ```
+    #[resolve]
+    pub max_indent: Option<Length>,
+
+    /// Content to fill the space between the title and the page number. Can be
+    /// set to `none` to disable filling.
+    ///
+    /// ```example
+    /// #outline(fill: line(length: 100%))
+    ///
+    /// = A New Beginning
+    /// ```
+    ///
+    /// The fill can also be a [function]($function). That function receives
+    /// the nesting level of the entry as a parameter (starting at 0 for
+    /// top-level headings/elements, just like for `indent`) and can return
+    /// content or `{none}`.
+    ///
+    /// ```example
+    /// #outline(fill: n => if n == 0 {
+    ///   line(length: 100%)
+    /// } else {
+    ///   repeat[.]
+    /// })
+    ///
+    /// = Introduction
+    /// == Scope
+    /// ```
+    ///
+    /// Instead of a function, you can also pass an array of fills, which is
+    /// indexed by the nesting level in the same way. Its items can be content
+    /// or `{none}`. Entries nested deeper than the array is long use its last
+    /// item.
+    ///
+    /// ```example
+    /// #outline(fill: (line(length: 100%), repeat[.]))
+    ///
+    /// = Introduction
+    /// == Scope
+    /// === Limitations
+    /// ```
+    ///
+    /// A [repeat]($repeat) fill only shows complete dots by default. To run
+    /// the dots up to the page number instead, clipping the last one, disable
+    /// its [`complete`]($repeat.complete) option.
+    ///
+    /// ```example
+    /// #outline(fill: repeat(complete: false)[.])
+    ///
+    /// = Introduction
+    /// ```
+    ///
+    /// With `{none}`, the page number is still pushed to the end of the line.
+    /// A length instead puts just this fixed gap between the title and the
+    /// page number. Entries that are clipped with an
+    /// [ellipsis]($outline.ellipsis) always span the full line.
+    ///
+    /// ```example
+    /// #outline(fill: 1em)
+    ///
+    /// = Introduction
+    /// = Background
+    /// ```
+    #[default(Some(OutlineFill::Content(
+        RepeatElem::new(TextElem::packed(".")).pack()
+    )))]
+    pub fill: Option<OutlineFill>,
+
+    /// The nesting level from which on entries are filled, starting at `{1}`
+    /// for top-level entries. Entries nested less deeply than this have no
+    /// fill, but their page number is still pushed to the end of the line.
+    ///
+    /// ```example
+    /// #outline(fill-from-level: 2)
+    ///
+    /// = Introduction
+    /// == Scope
+    /// == Limitations
+    /// ```
+    #[default(NonZeroUsize::ONE)]
+    pub fill_from_level: NonZeroUsize,
+
+    /// Functions to style the bodies of the entries with, indexed by their
+    /// nesting level (starting at 0 for top-level entries, just like for
+    /// `fill`). Entries nested deeper than the array is long use its last
+    /// item. An item of `{none}` leaves the bodies at its level as they are.
+    ///
+    /// ```example
+    /// #outline(level-style: (strong, none, emph))
+    ///
+    /// = Introduction
+    /// == Scope
+    /// === Limitations
+    /// ```
+    pub level_style: Vec<Option<Func>>,
+
+    /// A function that assembles each entry by itself.
+    ///
+    /// It receives the nesting level of the entry's element, its body and its
+    /// page number (or `{none}` if it has none) and returns the entry's
+    /// content, which is then linked to the element. This replaces the whole
+    /// built-in assembly of the entry, so `indent` and `fill` are ignored.
+    ///
+    /// ```example
+    /// #outline(entry: (level, body, page) => {
+    ///   [#level: #body (p. #page)]
+    /// })
+    ///
+    /// = Introduction
+    /// == Scope
+    /// ```
+    pub entry: Option<Func>,
+
+    /// Whether to display the page numbers of the outline's entries.
+    ///
+    /// - `{auto}`: Displays the page number of every entry. This is the
+    ///   default.
+    /// - `{none}`: Displays no page numbers at all.
+    /// - [Function]($function): Receives each outlined element and returns
+    ///   whether its page number should be displayed.
+    ///
+    /// Entries without a page number are still linked to their element, but
+    /// are not followed by a fill.
+    ///
+    /// ```example
+    /// #outline(
+    ///   page-numbers: it => it.level > 1,
+    /// )
+    ///
+    /// = Preface
+    /// == Acknowledgements
+    /// ```
+    #[default(Some(Smart::Auto))]
+    pub page_numbers: Option<Smart<Func>>,
+
+    /// Whether to display the range of pages an entry spans instead of just
+    /// its first page.
+    ///
+    /// An entry spans the pages up to the one on which the next element at
+    /// the same or a higher level starts. The last one spans the rest of the
+    /// document. Entries that start and end on the same page still display a
+    /// single page number.
+    ///
+    /// ```example
+    /// #outline(page-range: true)
+    ///
+    /// = Introduction
+    /// #lorem(30)
+    /// #pagebreak()
+    /// = Conclusion
+    /// ```
+    #[default(false)]
+    pub page_range: bool,
+
+    /// Whether to display the total number of pages along with each entry's
+    /// page number.
+    ///
+    /// Just like [`counter(page).display(both: true)`]($counter.display),
+    /// this displays both the current and the final value of the page
+    /// counter with the numbering of the page. Just like in the page's header
+    /// or footer, this already happens for numbering functions and for
+    /// numberings with at least two counting symbols or levels. For other
+    /// numberings, this option enables it.
+    ///
+    /// ```example
+    /// #set page(numbering: "1 / 1")
+    /// #outline(page-total: true)
+    ///
+    /// = Introduction
+    /// ```
+    #[default(false)]
+    pub page_total: bool,
+
+    /// How to number the pages of the outline's entries.
+    ///
+    /// When `{auto}`, each page number is displayed with the numbering of
+    /// the page the entry's element is located on. Otherwise, this
+    /// [numbering pattern or function]($numbering) is used for all entries,
+    /// for example to display the pages of roman-numbered front matter with
+    /// arabic numbers, too.
+    ///
+    /// ```example
+    /// #set page(numbering: "i")
+    /// #outline(page-numbering: "1")
+    ///
+    /// = Preface
+    /// ```
+    pub page_numbering: Smart<Numbering>,
+
+    /// A function that annotates entries in a column after their page
+    /// number, like with a status or a date.
+    ///
+    /// The function receives each outlined element and returns the content of
+    /// its annotation or `{none}`. The annotation column has a fixed width,
+    /// so that the page numbers of all entries stay aligned with each other.
+    ///
+    /// ```example
+    /// #outline(annotation: it => {
+    ///   if it.level == 1 [Draft]
+    /// })
+    ///
+    /// = Introduction
+    /// == Scope
+    /// ```
+    pub annotation: Option<Func>,
+
+    /// The width of the column with the [annotations]($outline.annotation).
+    #[resolve]
+    #[default(Em::new(3.0).into())]
+    pub annotation_width: Length,
+
+    /// Where the entries of the outline link to.
+    ///
+    /// By default, an entry links to the element it refers to. When set to
+    /// `{"page"}`, it links to the top of the page containing that element
+    /// instead.
+    ///
+    /// ```example
+    /// #outline(link-target: "page")
+    ///
+    /// = Introduction
+    /// #lorem(10)
+    /// ```
+    #[default(OutlineLinkTarget::Element)]
+    pub link_target: OutlineLinkTarget,
+
+    /// Where to place the page numbers of the outline's entries.
+    ///
+    /// - `{end}`: Places the page number at the end of the line, separated
+    ///   from the entry's body by the `fill`. This is the default.
+    /// - `{start}`: Places the page number at the start of the line, followed
+    ///   by the indented body. In this case, there is no fill.
+    ///
+    /// ```example
+    /// #outline(
+    ///   indent: 1em,
+    ///   page-position: start,
+    /// )
+    ///
+    /// = Introduction
+    /// == Scope
+    /// ```
+    #[default(HAlign::End)]
+    #[parse({
+        let option: Option<Spanned<HAlign>> = args.named("page-position")?;
+        if let Some(Spanned { v: align, span }) = option {
+            if !matches!(align, HAlign::Start | HAlign::End) {
+                bail!(span, "expected `start` or `end`");
+            }
+        }
+        option.map(|spanned| spanned.v)
+    })]
+    pub page_position: HAlign,
+
+    /// The spacing between the outline's entries.
+    ///
+    /// When this is `{none}`, the entries are just separated by line breaks.
+    /// Otherwise, the given spacing is inserted after each entry in place of
+    /// the usual paragraph spacing. Like with [`v`]($v), the spacing can be
+    /// absolute, relative, or fractional.
+    ///
+    /// ```example
+    /// #outline(gap: 0.8em)
+    ///
+    /// = Introduction
+    /// = Background
+    /// = Analysis
+    /// ```
+    pub gap: Option<Spacing>,
+
+    /// How to number the outline's entries.
+    ///
+    /// - `{auto}`: The entries are numbered just like their elements.
+    /// - `{none}`: The entries are not numbered.
+    /// - A [numbering pattern or function]($numbering): The entries are
+    ///   renumbered from their elements' counters without affecting the
+    ///   numbers in the document. Elements that aren't numbered themselves
+    ///   are numbered in the order in which they are listed, separately for
+    ///   each nesting level.
+    ///
+    /// The nesting of the entries is not affected by this setting.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.1")
+    /// #outline(numbering: "A.1")
+    ///
+    /// = Introduction
+    /// == Motivation
+    /// = Background
+    /// ```
+    #[default(Smart::Auto)]
+    pub numbering: Smart<Option<Numbering>>,
+
+    /// The number of columns the outline's entries are laid out in.
+    ///
+    /// The title is not part of the columns. Just like with the
+    /// [`columns`]($columns) function, the entries fill up each column before
+    /// moving on to the next one.
+    ///
+    /// ```example
+    /// #outline(columns: 2)
+    ///
+    /// = Introduction
+    /// = Background
+    /// = Analysis
+    /// = Conclusion
+    /// ```
+    #[default(NonZeroUsize::ONE)]
+    pub columns: NonZeroUsize,
+
+    /// Content to end entries with that don't fit on a single line.
+    ///
+    /// When set, an entry's body is cut off right before it would push the
+    /// fill and page number onto the next line and this content is appended
+    /// to it instead. The cut-off body keeps only its plain text. When
+    /// `{none}`, long entries are broken across lines as usual.
+    ///
+    /// ```example
+    /// #set page(width: 150pt)
+    /// #outline(ellipsis: […])
+    ///
+    /// = Introduction
+    /// = A heading that is far too long for one line
+    /// ```
+    #[default(None)]
+    pub ellipsis: Option<Content>,
+
+    /// The spacing between the number of an entry and its title.
+    ///
+    /// When this is `{none}`, each element separates its number from its
+    /// title in its own way: Headings use a space and figures use the
+    /// [separator]($figure.caption.separator) of their caption. Otherwise,
+    /// this spacing replaces that separator.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.")
+    /// #outline(number-gap: 1.5em)
+    ///
+    /// = Introduction
+    /// = Background
+    /// ```
+    #[default(None)]
+    pub number_gap: Option<Spacing>,
+
+    /// Whether to align the titles of entries at the same nesting level.
+    ///
+    /// Numbers like "9." and "10." differ in width, which makes the titles
+    /// after them start at different positions. When this is enabled, the
+    /// numbers of entries at the same level are padded to the width of the
+    /// widest one among them.
+    ///
+    /// ```example
+    /// #set heading(numbering: "1.")
+    /// #outline(align-titles: true)
+    ///
+    /// #counter(heading).update(8)
+    /// = Ninth
+    /// = Tenth
+    /// ```
+    #[default(false)]
+    pub align_titles: bool,
+
+    /// Whether the wrapped lines of an entry start below its title.
+    ///
+    /// By default, an entry that doesn't fit on a single line continues at
+    /// the start of the next line, right below the entry's number. When this
+    /// is enabled, the continuation lines are indented by the width of the
+    /// indent and number in front of the title instead. The fill and page
+    /// number stay on the last line.
+    ///
+    /// ```example
+    /// #set page(width: 150pt)
+    /// #set heading(numbering: "1.")
+    /// #outline(hanging-indent: true)
+    ///
+    /// = A heading that is far too long for one line
+    /// ```
+    #[default(false)]
+    pub hanging_indent: bool,
+
+    /// Whether to group the entries by the kind of their element.
+    ///
+    /// - `{false}`: The entries are not grouped. This is the default.
+    /// - `{true}`: The entries of each kind of figure are listed together
+    ///   below a subheading, in the order in which the kinds first appear.
+    ///   Other entries, like those of headings, are listed together without a
+    ///   subheading.
+    /// - `{"consecutive"}`: The entries stay in document order and a
+    ///   subheading is inserted wherever the kind of figure changes from one
+    ///   entry to the next.
+    ///
+    /// The subheadings of image and table figures are named like "Figures"
+    /// and "Tables" in the [text language]($text.lang). Other kinds are named
+    /// with the figure's supplement.
+    ///
+    /// ```example
+    /// #outline(
+    ///   title: [Figures],
+    ///   target: figure,
+    ///   grouped: true,
+    /// )
+    ///
+    /// #figure(rect(), caption: [A box])
+    /// #figure(table[A], caption: [A table])
+    /// #figure(circle(), caption: [A circle])
+    /// ```
+    #[default(OutlineGrouping::None)]
+    pub grouped: OutlineGrouping,
+}
+
+#[scope]
+impl OutlineElem {
+    #[elem]
+    type OutlineEntry;
+}
+
+impl OutlineElem {
+    /// Whether the title is set explicitly, on the outline itself or through a
+    /// set rule, rather than left to its default. This tells an explicit
+    /// `{none}` or `{auto}` apart from the default `{auto}`.
+    pub fn title_is_set(&self, styles: StyleChain) -> bool {
+        let inherent = self.0.field("title");
+        styles
+            .properties::<Value>(Self::elem(), "title", inherent)
+            .next()
+            .is_some()
+    }
+
+    /// The title to use when it is `auto`. Outlines of image or table figures
+    /// are titled as lists of figures or tables.
+    fn default_title(&self, styles: StyleChain) -> &'static str {
+        let lang = TextElem::lang_in(styles);
+        let region = TextElem::region_in(styles);
+        let kind = match self.target(styles).0 {
+            Selector::Elem(elem, Some(dict)) if elem == FigureElem::elem() => dict
+                .get("kind")
+                .ok()
+                .and_then(|kind| kind.clone().cast::<Func>().ok())
+                .and_then(|kind| kind.element()),
+            _ => None,
+        };
+
+        match kind {
+            Some(kind) if kind == ImageElem::elem() => list_of_figures_name(lang, region),
+            Some(kind) if kind == TableElem::elem() => list_of_tables_name(lang, region),
+            _ => self.local_name(lang, region),
+        }
+    }
+
+    /// Restrict a selector to the elements in the section this outline, which
+    /// is at the given location, is located in.
+    fn restrict_to_section(
+        &self,
+        vt: &Vt,
+        location: Location,
+        selector: Selector,
+    ) -> Selector {
+        // Only outlined headings delimit sections, which also keeps the
+        // outline's own title from ending the section.
+        let headings =
+            Selector::Elem(HeadingElem::elem(), Some(dict! { "outlined" => true }));
+        let level = |heading: &Content| {
+            heading.to::<HeadingElem>().unwrap().level(StyleChain::default())
+        };
+
+        // The section starts at the last heading before the outline.
+        let before = Selector::Before {
+            selector: Arc::new(headings.clone()),
+            end: Arc::new(Selector::Location(location)),
+            inclusive: false,
+        };
+        let Some(section) = vt.introspector.query(&before).last().cloned() else {
+            return selector;
+        };
+        let Some(start) = section.location() else {
+            return selector;
+        };
+
+        // It ends at the next heading that isn't nested in it.
+        let start = Arc::new(Selector::Location(start));
+        let after = Selector::After {
+            selector: Arc::new(headings),
+            start: start.clone(),
+            inclusive: false,
+        };
+        let end = vt
+            .introspector
+            .query(&after)
+            .into_iter()
+            .find(|heading| level(heading) <= level(&section))
+            .and_then(|heading| heading.location());
+
+        let mut selector = Selector::After {
+            selector: Arc::new(selector),
+            start,
+            inclusive: false,
+        };
+        if let Some(end) = end {
+            selector = Selector::Before {
+                selector: Arc::new(selector),
+                end: Arc::new(Selector::Location(end)),
+                inclusive: false,
+            };
+        }
+
+        selector
+    }
+}
+
+impl Show for OutlineElem {
+    #[tracing::instrument(name = "OutlineElem::show", skip_all)]
+    fn show(&self, vt: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
+        let Some(here) = self.0.location() else {
+            bail!(self.span(), "outline must be located to be shown");
+        };
+
+        let filter = self.filter(styles);
+        let indent = self.indent(styles);
+        let max_indent = self.max_indent(styles);
+        let fill = self.fill(styles);
+        let fill_from_level = self.fill_from_level(styles);
+        let level_style = self.level_style(styles);
+        let template = self.entry(styles);
+        let page_numbers = self.page_numbers(styles);
+        let sort = self.sort(styles);
+        let page_range = self.page_range(styles) && sort.is_none();
+        let page_total = self.page_total(styles);
+        let page_numbering = self.page_numbering(styles);
+        let annotation = self.annotation(styles);
+        let annotation_width = self.annotation_width(styles);
+        let link_target = self.link_target(styles);
+        let page_position = self.page_position(styles);
+        let gap = self.gap(styles);
+        let numbering = self.numbering(styles);
+        let ellipsis = self.ellipsis(styles);
+        let number_gap = self.number_gap(styles);
+        let align_titles = self.align_titles(styles);
+        let hanging_indent = self.hanging_indent(styles);
+        let (min, max) = match self.depth(styles) {
+            None => (NonZeroUsize::ONE, None),
+            Some(OutlineDepth::Max(max)) => (NonZeroUsize::ONE, Some(max)),
+            Some(OutlineDepth::Range(min, max)) => (min, max),
+        };
+        let start = self.start(styles).max(min);
+        let max_page = self.max_page(styles);
+        if max.is_some_and(|max| start > max) {
+            bail!(self.span(), "outline start must not be greater than its depth");
+        }
+
+        let mut ancestors: Vec<&Content> = vec![];
+        let mut entries = vec![];
+        let mut groups = vec![];
+
+        // The entries whose numbers are padded once the widest number of
+        // each level is known.
+        let mut widths = HashMap::new();
+        let mut aligned = vec![];
+
+        // The entries whose wrapped lines are indented, along with what
+        // precedes their number on the first line.
+        let mut hanging = vec![];
+
+        // The numbers of the entries that are numbered by the outline itself.
+        let mut counts = CounterState(smallvec![0]);
+        let mut target = self.target(styles).0;
+        if self.local(styles) {
+            target = self.restrict_to_section(vt, here, target);
+        }
+
+        let mut elems = vt.introspector.query(&target);
+
+        // Outlines can't be outlined, as they would end up in themselves.
+        let is_outline = |elem: &Prehashed<Content>| elem.func() == Self::elem();
+        if elems.iter().any(is_outline) {
+            vt.tracer.warn(
+                warning!(self.span(), "outlines cannot be outlined")
+                    .with_hint("they are left out of the outline"),
+            );
+            elems = elems.into_iter().filter(|elem| !is_outline(elem)).collect();
+        }
+        if let Some(key) = &sort {
+            elems = sort_by_key(vt, elems, key, self.span())?;
+        }
+
+        // A malformed element without a location can't be linked to, but
+        // shouldn't keep the rest of the outline from being built.
+        let (elems, unlocated) = with_locations(elems);
+        for elem in unlocated {
+            vt.tracer.warn(warning!(
+                self.span(),
+                "cannot outline {} without a location",
+                elem.func().name(),
+            ));
+        }
+
+        // Only the elements that pass the filter and the depth bounds are
+        // listed. The root is picked among them, so that an element that is
+        // left out doesn't indent the others.
+        let mut listed = vec![];
+        for (i, (location, elem)) in elems.iter().enumerate() {
+            let location = *location;
+            if let Some(filter) = &filter {
+                let keep = filter
+                    .call_vt(vt, [elem.clone().into_inner()])?
+                    .cast::<bool>()
+                    .at(self.span())?;
+                if !keep {
+                    continue;
+                }
+            }
+
+            let Some(entry) = OutlineEntry::from_outlinable(
+                vt,
+                self.span(),
+                elem.clone().into_inner(),
+                location,
+                &numbering,
+                &page_numbering,
+                page_total,
+            )?
+            else {
+                continue;
+            };
+
+            let level = entry.level();
+            // Both bounds are inclusive and without a maximum, there is no
+            // upper bound at all.
+            if level < start || max.is_some_and(|max| level > max) {
+                continue;
+            }
+
+            if let Some(max_page) = max_page {
+                let page = Counter::new(CounterKey::Page).at(vt, location)?.first();
+                if page > max_page.get() {
+                    continue;
+                }
+            }
+
+            listed.push((i, location, elem, entry));
+        }
+
+        // The root is the parent of all other entries, but not an ancestor
+        // that is tracked along the hierarchy.
+        let root = match self.root(styles) {
+            None => None,
+            Some(Smart::Auto) => {
+                listed.iter().map(|&(_, _, elem, _)| elem).find(|elem| {
+                    elem.to::<HeadingElem>().is_some_and(|heading| {
+                        heading.outlined(StyleChain::default())
+                            && heading.level(StyleChain::default()) == NonZeroUsize::ONE
+                    })
+                })
+            }
+            Some(Smart::Custom(label)) => listed
+                .iter()
+                .map(|&(_, _, elem, _)| elem)
+                .find(|elem| elem.label() == Some(&label)),
+        };
+        let root = root.map(|root| &**root);
+
+        for (i, location, elem, mut entry) in listed {
+            let level = entry.level();
+            let numbered = match &page_numbers {
+                None => false,
+                Some(Smart::Auto) => true,
+                Some(Smart::Custom(func)) => func
+                    .call_vt(vt, [elem.clone().into_inner()])?
+                    .cast::<bool>()
+                    .at(self.span())?,
+            };
+
+            if !numbered {
+                entry.push_page(Content::empty());
+            }
+
+            // Look ahead for the element that ends this one's section.
+            let page = entry.page();
+            if page_range && !page.is_empty() {
+                let next = elems[i + 1..].iter().find(|(_, next)| {
+                    next.with::<dyn Outlinable>().map_or(false, |next| {
+                        next.participates_in_hierarchy() && next.level() <= level
+                    })
+                });
+
+                let numbering = resolve_page_numbering(vt, &page_numbering, location);
+                let both = page_total || numbering.displays_total();
+                let counter = Counter::new(CounterKey::Page);
+                let (last, state) = match next.map(|&(next, _)| next) {
+                    Some(next) if both => {
+                        (vt.introspector.page(next), counter.both(vt, next)?)
+                    }
+                    Some(next) => (vt.introspector.page(next), counter.at(vt, next)?),
+                    None if both => {
+                        let last = counter.final_(vt, location)?.first();
+                        (vt.introspector.pages(), CounterState(smallvec![last, last]))
+                    }
+                    None => (vt.introspector.pages(), counter.final_(vt, location)?),
+                };
+
+                if last > vt.introspector.page(location) {
+                    let end = state.display(vt, &numbering)?;
+                    entry.push_page(page + TextElem::packed('–') + end);
+                }
+            }
+
+            // Elements without a number of their own are numbered by the
+            // outline itself, in the order in which they are listed.
+            let outlinable = elem.with::<dyn Outlinable>().unwrap();
+            let mut split = None;
+            let mut sequential = false;
+            if let Smart::Custom(Some(numbering)) = &numbering {
+                if outlinable.numbering().is_none() {
+                    counts.step(level, 1);
+                    let number = counts.display(vt, numbering)?;
+                    let separator = SpaceElem::new().pack();
+                    split =
+                        Some(OutlineParts::new(Some(number), separator, entry.body()));
+                    sequential = true;
+                }
+            }
+
+            // Split off the number to align the title after it or to put the
+            // number gap in between.
+            if !sequential && (align_titles || hanging_indent || number_gap.is_some()) {
+                let own = match &numbering {
+                    Smart::Auto => outlinable.numbering(),
+                    Smart::Custom(numbering) => numbering.clone(),
+                };
+                split = outlinable.outline_parts(vt, own.as_ref())?;
+            }
+
+            let mut split = split.filter(|parts| parts.number.is_some());
+            if let Some(parts) = &mut split {
+                if let Some(gap) = number_gap {
+                    parts.separator = HElem::new(gap).pack();
+                }
+                if sequential || number_gap.is_some() {
+                    entry.push_body(parts.clone().join());
+                }
+            }
+
+            // Some elements want something in front of their number, like
+            // "Chapter".
+            let prefix = outlinable.prefix(vt)?;
+            if let Some(prefix) = &prefix {
+                entry.push_body(prefix.clone() + SpaceElem::new().pack() + entry.body());
+            }
+
+            // A custom entry is assembled entirely by the user.
+            if let Some(template) = &template {
+                let page = entry.page();
+                let page = if page.is_empty() { Value::None } else { page.into_value() };
+                let args = [level.get().into_value(), entry.body().into_value(), page];
+                let content = template
+                    .call_vt(vt, args)?
+                    .display()
+                    .linked(Destination::Location(location));
+
+                let mut line = vec![content, LinebreakElem::new().pack()];
+                if let Some(gap) = gap {
+                    line.push(VElem::weak(gap).pack());
+                }
+                entries.push(line);
+                groups.push(outlinable.group());
+                continue;
+            }
+
+            let mut parts = None;
+            if align_titles || hanging_indent {
+                if let Some(OutlineParts { number: Some(mut number), separator, title }) =
+                    split
+                {
+                    if let Some(prefix) = prefix {
+                        number = prefix + SpaceElem::new().pack() + number;
+                    }
+
+                    let pod = Regions::one(Axes::splat(Abs::inf()), Axes::splat(false));
+                    let width = number.measure(vt, styles, pod)?.into_frame().width();
+                    if align_titles {
+                        let widest = widths.entry(level).or_insert(Abs::zero());
+                        *widest = widest.max(width);
+                    }
+                    parts = Some((number, separator, title, width));
+                }
+            }
+
+            // Deals with the ancestors of the current element.
+            // This is only applicable for elements with a hierarchy/level.
+            let hierarchical = outlinable.participates_in_hierarchy() && sort.is_none();
+            let is_ancestor = |ancestor: &&Content| {
+                ancestor
+                    .with::<dyn Outlinable>()
+                    .map_or(false, |ancestor| ancestor.level() < level)
+            };
+
+            if hierarchical {
+                while ancestors.last().map_or(false, |last| !is_ancestor(last)) {
+                    ancestors.pop();
+                }
+            }
+
+            // Elements outside of the hierarchy are nested like the others, but
+            // don't become the parent of the following elements.
+            let parents =
+                ancestors.iter().take_while(|ancestor| is_ancestor(ancestor)).count();
+            let is_root = root.is_some_and(|root| root.location() == Some(location));
+            let mut nested: Vec<&Content> =
+                root.into_iter().filter(|_| !is_root).collect();
+            nested.extend(&ancestors[..parents]);
+            let parents = nested.as_slice();
+
+            // The fill may depend on the nesting level of the entry.
+            let filler = match &fill {
+                Some(fill) if parents.len() + 1 >= fill_from_level.get() => {
+                    fill.resolve(vt, parents.len(), self.span())?
+                }
+                _ => None,
+            };
+            entry.push_fill(filler);
+            entry.push_ellipsis(ellipsis.clone());
+            entry.push_link_target(link_target);
+
+            if let Some(annotate) = &annotation {
+                let content = annotate
+                    .call_vt(vt, [elem.clone().into_inner()])?
+                    .cast::<Option<Content>>()
+                    .at(self.span())?;
+                let column = BoxElem::new()
+                    .with_body(content)
+                    .with_width(annotation_width.into())
+                    .pack();
+                entry.push_annotation(Some(column));
+            }
+
+            let mut line = vec![];
+            if page_position == HAlign::Start {
+                // The indent applies to the body, which follows the page
+                // number, so the entry has to place it itself.
+                let mut indentation = vec![];
+                OutlineIndent::apply(
+                    &indent,
+                    max_indent,
+                    vt,
+                    styles,
+                    parents,
+                    elem,
+                    &mut indentation,
+                    self.span(),
+                )?;
+                entry.push_indent(Content::sequence(indentation));
+                entry.push_page_position(page_position);
+            } else {
+                OutlineIndent::apply(
+                    &indent,
+                    max_indent,
+                    vt,
+                    styles,
+                    parents,
+                    elem,
+                    &mut line,
+                    self.span(),
+                )?;
+            }
+
+            if hanging_indent {
+                let mut prefix = line.clone();
+                if page_position == HAlign::Start {
+                    let page = entry.page();
+                    if !page.is_empty() {
+                        prefix.push(page);
+                        prefix.push(SpaceElem::new().pack());
+                    }
+                    prefix.push(entry.indent(styles));
+                }
+
+                let number = parts
+                    .as_ref()
+                    .map(|(_, separator, _, width)| (*width, separator.clone()));
+
+                hanging.push((entries.len(), line.len(), level, prefix, number));
+            }
+
+            // The body may be styled depending on the nesting level.
+            let style = level_style.get(parents.len()).or(level_style.last()).cloned();
+            let style = style.flatten();
+            if let Some(style) = &style {
+                entry.push_body(style.call_vt(vt, [entry.body()])?.display());
+            }
 
-#[scope]
-impl OutlineElem {
-    #[elem]
-    type OutlineEntry;
-}
+            // Add the overridable outline entry, followed by a line break.
+            if let Some((number, separator, title, _)) = parts.filter(|_| align_titles) {
+                let rest = separator + title;
+                aligned.push((
+                    entries.len(),
+                    line.len(),
+                    entry.clone(),
+                    number,
+                    rest,
+                    style,
+                ));
+            }
+            line.push(entry.pack());
+            if hanging_indent {
+                // An entry with a hanging indent needs a paragraph of its own,
+                // which is spaced just like the lines of a paragraph.
+                let leading = ParElem::leading_in(styles);
+                line.push(ParbreakElem::new().pack());
+                line.push(VElem::weak(gap.unwrap_or(leading.into())).pack());
+            } else {
+                line.push(LinebreakElem::new().pack());
+                if let Some(gap) = gap {
+                    line.push(VElem::weak(gap).pack());
+                }
+            }
+            entries.push(line);
+            groups.push(outlinable.group());
+
+            if hierarchical && !is_root {
+                ancestors.push(elem);
+            }
+        }
+
+        if entries.is_empty() && self.hide_empty(styles) {
+            return Ok(Content::empty());
+        }
 
-impl Show for OutlineElem {
-    #[tracing::instrument(name = "OutlineElem::show", skip_all)]
-    fn show(&self, vt: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
         let mut seq = vec![ParbreakElem::new().pack()];
         // Build the outline title.
         if let Some(title) = self.title(styles) {
-            let title =
-                title.unwrap_or_else(|| {
-                    TextElem::packed(self.local_name(
-                        TextElem::lang_in(styles),
-                        TextElem::region_in(styles),
-                    ))
-                    .spanned(self.span())
-                });
+            let title = match title {
+                Smart::Auto => {
+                    TextElem::packed(self.default_title(styles)).spanned(self.span())
+                }
+                Smart::Custom(OutlineTitle::Content(content)) => content,
+                Smart::Custom(OutlineTitle::Func(func)) => {
+                    let page = Counter::new(CounterKey::Page).at(vt, here)?.first();
+                    let context = dict! { "entries" => entries.len(), "page" => page };
+                    func.call_vt(vt, [context])?.display()
+                }
+            };
 
-            seq.push(HeadingElem::new(title).with_level(NonZeroUsize::ONE).pack());
+            seq.push(match self.title_as(styles) {
+                OutlineTitleAs::Heading => HeadingElem::new(title)
+                    .with_level(NonZeroUsize::ONE)
+                    .with_outlined(false)
+                    .with_bookmarked(Smart::Custom(self.title_bookmarked(styles)))
+                    .pack(),
+                OutlineTitleAs::Content => BlockElem::new().with_body(Some(title)).pack(),
+            });
+
+            if let Some(gap) = self.title_gap(styles) {
+                seq.push(VElem::weak(gap).pack());
+            }
         }
 
-        let indent = self.indent(styles);
-        let depth = self.depth(styles).unwrap_or(NonZeroUsize::new(usize::MAX).unwrap());
+        // Pad the numbers to the widest one of their level.
+        for (i, j, mut entry, number, rest, style) in aligned {
+            let width = widths[&entry.level()];
+            let number = BoxElem::new().with_body(Some(number)).with_width(width.into());
+            let mut body = number.pack() + rest;
+            if let Some(style) = style {
+                body = style.call_vt(vt, [body])?.display();
+            }
+            entry.push_body(body);
+            entries[i][j] = entry.pack();
+        }
 
-        let mut ancestors: Vec<&Content> = vec![];
-        let elems = vt.introspector.query(&self.target(styles).0);
+        // Indent the wrapped lines by the width of everything in front of the
+        // title. A trailing box keeps a space after the number from being
+        // trimmed.
+        for (i, j, level, mut prefix, number) in hanging {
+            let mut width = Abs::zero();
+            prefix.push(BoxElem::new().pack());
+            if let Some((number, separator)) = number {
+                width += widths.get(&level).copied().unwrap_or(number);
+                prefix.push(separator);
+                prefix.push(BoxElem::new().pack());
+            }
 
-        for elem in &elems {
-            let Some(entry) = OutlineEntry::from_outlinable(
-                vt,
-                self.span(),
-                elem.clone().into_inner(),
-                self.fill(styles),
-            )?
-            else {
-                continue;
-            };
+            let pod = Regions::one(Axes::splat(Abs::inf()), Axes::splat(false));
+            width += Content::sequence(prefix)
+                .measure(vt, styles, pod)?
+                .into_frame()
+                .width();
 
-            let level = entry.level();
-            if depth < level {
-                continue;
-            }
+            let line = &mut entries[i];
+            let entry = Content::sequence(line.drain(..=j))
+                .styled(ParElem::set_hanging_indent(width.into()));
+            line.insert(0, entry);
+        }
 
-            // Deals with the ancestors of the current element.
-            // This is only applicable for elements with a hierarchy/level.
-            while ancestors
-                .last()
-                .and_then(|ancestor| ancestor.with::<dyn Outlinable>())
-                .map_or(false, |last| last.level() >= level)
-            {
-                ancestors.pop();
-            }
+        // The ancestors are always tracked in document order, so reversing
+        // only changes the order in which the finished lines are emitted.
+        if self.reverse(styles) {
+            entries.reverse();
+            groups.reverse();
+        }
 
-            OutlineIndent::apply(&indent, vt, &ancestors, &mut seq, self.span())?;
+        // Collect the entries of each group below a subheading, in the order
+        // in which the groups first appear.
+        let subheading = |name: Content| {
+            let level = NonZeroUsize::new(2).unwrap();
+            vec![HeadingElem::new(name).with_level(level).pack()]
+        };
 
-            // Add the overridable outline entry, followed by a line break.
-            seq.push(entry.pack());
-            seq.push(LinebreakElem::new().pack());
+        match self.grouped(styles) {
+            OutlineGrouping::None => {}
+            OutlineGrouping::Collected => {
+                let mut buckets: Vec<(Option<(Value, Content)>, Vec<_>)> = vec![];
+                for (line, group) in entries.drain(..).zip(groups) {
+                    let key = group.as_ref().map(|(key, _)| key);
+                    match buckets
+                        .iter_mut()
+                        .find(|(other, _)| other.as_ref().map(|(key, _)| key) == key)
+                    {
+                        Some((_, lines)) => lines.push(line),
+                        None => buckets.push((group, vec![line])),
+                    }
+                }
+
+                for (group, lines) in buckets {
+                    if let Some((key, name)) = group {
+                        entries.push(subheading(group_name(&key, name, styles)));
+                    }
+                    entries.extend(lines);
+                }
+            }
+            OutlineGrouping::Consecutive => {
+                let mut previous = None;
+                for (line, group) in std::mem::take(&mut entries).into_iter().zip(groups)
+                {
+                    let key = group.as_ref().map(|(key, _)| key.clone());
+                    if let Some((key, name)) =
+                        group.filter(|(key, _)| previous.as_ref() != Some(key))
+                    {
+                        entries.push(subheading(group_name(&key, name, styles)));
+                    }
+                    previous = key;
+                    entries.push(line);
+                }
+            }
+        }
 
-            ancestors.push(elem);
+        // The title stays above the columns and spans the full width.
+        let columns = self.columns(styles);
+        if columns.get() > 1 {
+            let body = Content::sequence(entries.into_iter().flatten());
+            seq.push(ColumnsElem::new(body).with_count(columns).pack());
+        } else {
+            seq.extend(entries.into_iter().flatten());
         }
 
         seq.push(ParbreakElem::new().pack());
@@ -248,9 +1536,12 @@ impl Show for OutlineElem {
 
 impl Finalize for OutlineElem {
     fn finalize(&self, realized: Content, _: StyleChain) -> Content {
+        // The entries are laid out as lines of paragraphs, which shouldn't
+        // pick up the document's first-line indent.
         realized
             .styled(HeadingElem::set_outlined(false))
             .styled(HeadingElem::set_numbering(None))
+            .styled(ParElem::set_first_line_indent(Length::zero()))
     }
 }
 
@@ -260,7 +1551,8 @@ impl LocalName for OutlineElem {
             Lang::ALBANIAN => "Përmbajtja",
             Lang::ARABIC => "المحتويات",
             Lang::BOKMÅL => "Innhold",
-            Lang::CHINESE if option_eq(region, "TW") => "目錄",
+            Lang::CATALAN => "Índex",
+            Lang::CHINESE if option_eq(region, "TW") || option_eq(region, "HK") => "目錄",
             Lang::CHINESE => "目录",
             Lang::CZECH => "Obsah",
             Lang::DANISH => "Indhold",
@@ -269,6 +1561,7 @@ impl LocalName for OutlineElem {
             Lang::FINNISH => "Sisällys",
             Lang::FRENCH => "Table des matières",
             Lang::GERMAN => "Inhaltsverzeichnis",
+            Lang::GREEK => "Περιεχόμενα",
             Lang::HUNGARIAN => "Tartalomjegyzék",
             Lang::ITALIAN => "Indice",
             Lang::NYNORSK => "Innhald",
@@ -284,21 +1577,382 @@ impl LocalName for OutlineElem {
             Lang::UKRAINIAN => "Зміст",
             Lang::VIETNAMESE => "Mục lục",
             Lang::JAPANESE => "目次",
+            Lang::KOREAN => "목차",
             Lang::ENGLISH | _ => "Contents",
         }
     }
 }
 
+/// The title of an outline of image figures in the given language.
+fn list_of_figures_name(lang: Lang, region: Option<Region>) -> &'static str {
+    match lang {
+        Lang::ARABIC => "قائمة الأشكال",
+        Lang::BOKMÅL => "Figurer",
+        Lang::CHINESE if option_eq(region, "TW") || option_eq(region, "HK") => "插圖",
+        Lang::CHINESE => "插图",
+        Lang::CZECH => "Seznam obrázků",
+        Lang::DANISH => "Figurer",
+        Lang::DUTCH => "Lijst van figuren",
+        Lang::FINNISH => "Kuvat",
+        Lang::FRENCH => "Table des figures",
+        Lang::GERMAN => "Abbildungsverzeichnis",
+        Lang::GREEK => "Κατάλογος σχημάτων",
+        Lang::HUNGARIAN => "Ábrák jegyzéke",
+        Lang::ITALIAN => "Elenco delle figure",
+        Lang::NYNORSK => "Figurar",
+        Lang::POLISH => "Spis rysunków",
+        Lang::PORTUGUESE => "Lista de figuras",
+        Lang::ROMANIAN => "Listă de figuri",
+        Lang::RUSSIAN => "Список иллюстраций",
+        Lang::SLOVENIAN => "Slike",
+        Lang::SPANISH => "Índice de figuras",
+        Lang::SWEDISH => "Figurer",
+        Lang::TURKISH => "Şekil Listesi",
+        Lang::UKRAINIAN => "Перелік ілюстрацій",
+        Lang::JAPANESE => "図目次",
+        Lang::KOREAN => "그림 목차",
+        Lang::ENGLISH | _ => "List of Figures",
+    }
+}
+
+/// The title of an outline of table figures in the given language.
+fn list_of_tables_name(lang: Lang, _: Option<Region>) -> &'static str {
+    match lang {
+        Lang::ARABIC => "قائمة الجداول",
+        Lang::BOKMÅL => "Tabeller",
+        Lang::CHINESE => "表格",
+        Lang::CZECH => "Seznam tabulek",
+        Lang::DANISH => "Tabeller",
+        Lang::DUTCH => "Lijst van tabellen",
+        Lang::FINNISH => "Taulukot",
+        Lang::FRENCH => "Liste des tableaux",
+        Lang::GERMAN => "Tabellenverzeichnis",
+        Lang::GREEK => "Κατάλογος πινάκων",
+        Lang::HUNGARIAN => "Táblázatok jegyzéke",
+        Lang::ITALIAN => "Elenco delle tabelle",
+        Lang::NYNORSK => "Tabellar",
+        Lang::POLISH => "Spis tabel",
+        Lang::PORTUGUESE => "Lista de tabelas",
+        Lang::ROMANIAN => "Listă de tabele",
+        Lang::RUSSIAN => "Список таблиц",
+        Lang::SLOVENIAN => "Tabele",
+        Lang::SPANISH => "Índice de tablas",
+        Lang::SWEDISH => "Tabeller",
+        Lang::TURKISH => "Tablo Listesi",
+        Lang::UKRAINIAN => "Перелік таблиць",
+        Lang::JAPANESE => "表目次",
+        Lang::KOREAN => "표 목차",
+        Lang::ENGLISH | _ => "List of Tables",
+    }
+}
+
+/// The name of a group of image figures in the given language.
+fn figures_name(lang: Lang, region: Option<Region>) -> &'static str {
+    match lang {
+        Lang::ARABIC => "الأشكال",
+        Lang::BOKMÅL => "Figurer",
+        Lang::CHINESE if option_eq(region, "TW") || option_eq(region, "HK") => "圖",
+        Lang::CHINESE => "图",
+        Lang::CZECH => "Obrázky",
+        Lang::DANISH => "Figurer",
+        Lang::DUTCH => "Figuren",
+        Lang::FINNISH => "Kuvat",
+        Lang::FRENCH => "Figures",
+        Lang::GERMAN => "Abbildungen",
+        Lang::GREEK => "Σχήματα",
+        Lang::HUNGARIAN => "Ábrák",
+        Lang::ITALIAN => "Figure",
+        Lang::NYNORSK => "Figurar",
+        Lang::POLISH => "Rysunki",
+        Lang::PORTUGUESE => "Figuras",
+        Lang::ROMANIAN => "Figuri",
+        Lang::RUSSIAN => "Рисунки",
+        Lang::SLOVENIAN => "Slike",
+        Lang::SPANISH => "Figuras",
+        Lang::SWEDISH => "Figurer",
+        Lang::TURKISH => "Şekiller",
+        Lang::UKRAINIAN => "Рисунки",
+        Lang::JAPANESE => "図",
+        Lang::KOREAN => "그림",
+        Lang::ENGLISH | _ => "Figures",
+    }
+}
+
+/// The name of a group of table figures in the given language.
+fn tables_name(lang: Lang, _: Option<Region>) -> &'static str {
+    match lang {
+        Lang::ARABIC => "الجداول",
+        Lang::BOKMÅL => "Tabeller",
+        Lang::CHINESE => "表",
+        Lang::CZECH => "Tabulky",
+        Lang::DANISH => "Tabeller",
+        Lang::DUTCH => "Tabellen",
+        Lang::FINNISH => "Taulukot",
+        Lang::FRENCH => "Tableaux",
+        Lang::GERMAN => "Tabellen",
+        Lang::GREEK => "Πίνακες",
+        Lang::HUNGARIAN => "Táblázatok",
+        Lang::ITALIAN => "Tabelle",
+        Lang::NYNORSK => "Tabellar",
+        Lang::POLISH => "Tabele",
+        Lang::PORTUGUESE => "Tabelas",
+        Lang::ROMANIAN => "Tabele",
+        Lang::RUSSIAN => "Таблицы",
+        Lang::SLOVENIAN => "Tabele",
+        Lang::SPANISH => "Tablas",
+        Lang::SWEDISH => "Tabeller",
+        Lang::TURKISH => "Tablolar",
+        Lang::UKRAINIAN => "Таблиці",
+        Lang::JAPANESE => "表",
+        Lang::KOREAN => "표",
+        Lang::ENGLISH | _ => "Tables",
+    }
+}
+
 /// Marks an element as being able to be outlined. This is used to implement the
 /// `#outline()` element.
 pub trait Outlinable: Refable {
     /// Produce an outline item for this element.
     fn outline(&self, vt: &mut Vt) -> SourceResult<Option<Content>>;
 
+    /// Produce an outline item for this element, numbered with the given
+    /// numbering instead of the element's own one. Elements that aren't
+    /// numbered themselves stay unnumbered.
+    fn outline_numbered(
+        &self,
+        vt: &mut Vt,
+        _numbering: Option<&Numbering>,
+    ) -> SourceResult<Option<Content>> {
+        self.outline(vt)
+    }
+
+    /// Produce an outline item for this element that already contains its
+    /// page number, for elements that want to format both as one unit.
+    ///
+    /// When this returns `None`, the outline falls back to the regular
+    /// outline item and adds the page number by itself.
+    fn outline_with_page(
+        &self,
+        _vt: &mut Vt,
+        _page: &OutlinePage,
+    ) -> SourceResult<Option<Content>> {
+        Ok(None)
+    }
+
+    /// Produce an outline item for this element, numbered with the given
+    /// numbering, split into its number and its title. Joining the parts
+    /// yields the full item.
+    ///
+    /// This is used to align the titles of several entries and to put the
+    /// outline's number gap between number and title. By default, the item is
+    /// not split and has no separate number.
+    fn outline_parts(
+        &self,
+        vt: &mut Vt,
+        numbering: Option<&Numbering>,
+    ) -> SourceResult<Option<OutlineParts>> {
+        Ok(self.outline_numbered(vt, numbering)?.map(OutlineParts::unnumbered))
+    }
+
+    /// Produce content to show in front of this element's number in the
+    /// outline, like "Chapter".
+    fn prefix(&self, _vt: &mut Vt) -> SourceResult<Option<Content>> {
+        Ok(None)
+    }
+
+    /// The group this element belongs to in an outline whose entries are
+    /// grouped, as a key identifying it and a name to show above its entries.
+    ///
+    /// The entries of elements without a group are listed together without a
+    /// name.
+    fn group(&self) -> Option<(Value, Content)> {
+        None
+    }
+
     /// Returns the nesting level of this element.
     fn level(&self) -> NonZeroUsize {
         NonZeroUsize::ONE
     }
+
+    /// Whether this element can be the parent of the elements following it
+    /// in the outline. Elements outside of the hierarchy, like figures, are
+    /// indented according to their own level, but never become the parent of
+    /// another element.
+    fn participates_in_hierarchy(&self) -> bool {
+        true
+    }
+}
+
+/// An outline item split into its parts, as returned by
+/// [`Outlinable::outline_parts`].
+#[derive(Debug, Clone)]
+pub struct OutlineParts {
+    /// The element's number, if any.
+    pub number: Option<Content>,
+    /// What separates the number from the title.
+    pub separator: Content,
+    /// The element's title.
+    pub title: Content,
+}
+
+impl OutlineParts {
+    /// Split an item into its element's number and title, with the given
+    /// separator in between.
+    pub fn new(number: Option<Content>, separator: Content, title: Content) -> Self {
+        Self { number, separator, title }
+    }
+
+    /// An item without a separate number.
+    pub fn unnumbered(title: Content) -> Self {
+        Self::new(None, Content::empty(), title)
+    }
+
+    /// The item without its number. Without a number, there is nothing to
+    /// separate, so this is just the title.
+    pub fn rest(&self) -> Content {
+        match self.number {
+            Some(_) => self.separator.clone() + self.title.clone(),
+            None => self.title.clone(),
+        }
+    }
+
+    /// Join the parts into the full item.
+    pub fn join(self) -> Content {
+        match &self.number {
+            Some(number) => number.clone() + self.rest(),
+            None => self.title,
+        }
+    }
+}
+
+/// Pair the elements with their locations. The elements without a location are
+/// returned separately.
+fn with_locations(
+    elems: EcoVec<Prehashed<Content>>,
+) -> (Vec<(Location, Prehashed<Content>)>, Vec<Prehashed<Content>>) {
+    let mut located = vec![];
+    let mut unlocated = vec![];
+    for elem in elems {
+        match elem.location() {
+            Some(location) => located.push((location, elem)),
+            None => unlocated.push(elem),
+        }
+    }
+    (located, unlocated)
+}
+
+/// Stably sort elements by the keys a function returns for them.
+fn sort_by_key(
+    vt: &mut Vt,
+    elems: EcoVec<Prehashed<Content>>,
+    key: &Func,
+    span: Span,
+) -> SourceResult<EcoVec<Prehashed<Content>>> {
+    let mut keyed = vec![];
+    for elem in elems {
+        keyed.push((key.call_vt(vt, [elem.clone().into_inner()])?, elem));
+    }
+
+    let mut result = Ok(());
+    keyed.sort_by(|(a, _), (b, _)| {
+        typst::eval::ops::compare(a, b).unwrap_or_else(|err| {
+            if result.is_ok() {
+                result = Err(err);
+            }
+            Ordering::Equal
+        })
+    });
+
+    result.at(span)?;
+    Ok(keyed.into_iter().map(|(_, elem)| elem).collect())
+}
+
+/// The numbering to display the page number of an element at the given
+/// location with. With `auto`, this is the numbering of its page.
+fn resolve_page_numbering(
+    vt: &Vt,
+    numbering: &Smart<Numbering>,
+    location: Location,
+) -> Numbering {
+    match numbering {
+        Smart::Auto => page_numbering_at(vt, location),
+        Smart::Custom(numbering) => numbering.clone(),
+    }
+}
+
+/// How the entries of an [`OutlineElem`] are grouped by the kind of their
+/// element.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum OutlineGrouping {
+    /// The entries are not grouped.
+    None,
+    /// The entries of each kind are collected below one subheading.
+    Collected,
+    /// A subheading is inserted wherever the kind changes.
+    Consecutive,
+}
+
+cast! {
+    OutlineGrouping,
+    self => match self {
+        Self::None => false.into_value(),
+        Self::Collected => true.into_value(),
+        Self::Consecutive => "consecutive".into_value(),
+    },
+    v: bool => if v { Self::Collected } else { Self::None },
+    "consecutive" => Self::Consecutive,
+}
+
+/// The name of a group of outline entries. Image and table figures get a
+/// localized name, other groups keep the name they were given.
+fn group_name(key: &Value, name: Content, styles: StyleChain) -> Content {
+    let lang = TextElem::lang_in(styles);
+    let region = TextElem::region_in(styles);
+    match key.clone().cast::<Func>().ok().and_then(|kind| kind.element()) {
+        Some(kind) if kind == ImageElem::elem() => {
+            TextElem::packed(figures_name(lang, region))
+        }
+        Some(kind) if kind == TableElem::elem() => {
+            TextElem::packed(tables_name(lang, region))
+        }
+        _ => name,
+    }
+}
+
+/// The page an outlined element is located on.
+#[derive(Debug, Clone)]
+pub struct OutlinePage {
+    /// The location of the element.
+    pub location: Location,
+    /// The numbering of the element's page.
+    pub numbering: Numbering,
+    /// The element's page number, displayed with the page numbering.
+    pub page: Content,
+}
+
+/// The levels of the elements included in an outline.
+#[derive(Debug, Copy, Clone, PartialEq, Hash)]
+pub enum OutlineDepth {
+    /// All levels up to a maximum level.
+    Max(NonZeroUsize),
+    /// The levels between a minimum and an optional maximum level.
+    Range(NonZeroUsize, Option<NonZeroUsize>),
+}
+
+cast! {
+    OutlineDepth,
+    self => match self {
+        Self::Max(max) => max.into_value(),
+        Self::Range(min, max) => array![min, max].into_value(),
+    },
+    max: NonZeroUsize => Self::Max(max),
+    array: Array => {
+        let mut iter = array.into_iter();
+        match (iter.next(), iter.next(), iter.next()) {
+            (Some(min), Some(max), None) => Self::Range(min.cast()?, max.cast()?),
+            _ => bail!("depth array must contain exactly two entries"),
+        }
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -309,10 +1963,14 @@ pub enum OutlineIndent {
 }
 
 impl OutlineIndent {
+    #[allow(clippy::too_many_arguments)]
     fn apply(
         indent: &Option<Smart<Self>>,
+        max_indent: Option<Abs>,
         vt: &mut Vt,
-        ancestors: &Vec<&Content>,
+        styles: StyleChain,
+        ancestors: &[&Content],
+        elem: &Content,
         seq: &mut Vec<Content>,
         span: Span,
     ) -> SourceResult<()> {
@@ -322,40 +1980,59 @@ impl OutlineIndent {
 
             // 'auto' | 'true' => use numbering alignment for indenting
             Some(Smart::Auto | Smart::Custom(OutlineIndent::Bool(true))) => {
-                // Add hidden ancestors numberings to realize the indent.
-                let mut hidden = Content::empty();
+                // Add hidden ancestors numberings to realize the indent. The
+                // numberings are separated by spaces just like the visible
+                // ones, and the last one is followed by a single space outside
+                // of the hidden content so that it doesn't depend on how
+                // adjacent spaces collapse.
+                let mut hidden = vec![];
                 for ancestor in ancestors {
                     let ancestor_outlinable = ancestor.with::<dyn Outlinable>().unwrap();
+                    let Some(location) = ancestor.location() else { continue };
 
                     if let Some(numbering) = ancestor_outlinable.numbering() {
                         let numbers = ancestor_outlinable
                             .counter()
-                            .at(vt, ancestor.location().unwrap())?
+                            .at(vt, location)?
                             .display(vt, &numbering)?;
 
-                        hidden += numbers + SpaceElem::new().pack();
+                        if !hidden.is_empty() {
+                            hidden.push(SpaceElem::new().pack());
+                        }
+                        hidden.push(numbers);
                     };
                 }
 
                 if !ancestors.is_empty() {
-                    seq.push(HideElem::new(hidden).pack());
+                    seq.push(HideElem::new(Content::sequence(hidden)).pack());
                     seq.push(SpaceElem::new().pack());
                 }
             }
 
-            // Length => indent with some fixed spacing per level
-            Some(Smart::Custom(OutlineIndent::Rel(length))) => {
-                seq.push(
+            // Length => indent with some fixed spacing per level, up to the
+            // maximum indent
+            Some(Smart::Custom(OutlineIndent::Rel(length))) => match max_indent {
+                None => seq.push(
                     HElem::new(Spacing::Rel(*length)).pack().repeat(ancestors.len()),
-                );
-            }
+                ),
+                Some(max) if !ancestors.is_empty() => {
+                    let mut total = *length * ancestors.len() as f64;
+                    total.abs = total.abs.resolve(styles).min(max).into();
+                    seq.push(HElem::new(Spacing::Rel(total)).pack());
+                }
+                Some(_) => {}
+            },
 
-            // Function => call function with the current depth and take
-            // the returned content
+            // Function => call function with the current depth (and the
+            // element, if it accepts it) and take the returned content
             Some(Smart::Custom(OutlineIndent::Func(func))) => {
                 let depth = ancestors.len();
-                let LengthOrContent(content) =
-                    func.call_vt(vt, [depth])?.cast().at(span)?;
+                let value = if func.arity().map_or(true, |arity| arity > 1) {
+                    func.call_vt(vt, [depth.into_value(), elem.clone().into_value()])?
+                } else {
+                    func.call_vt(vt, [depth])?
+                };
+                let LengthOrContent(content) = value.cast().at(span)?;
                 if !content.is_empty() {
                     seq.push(content);
                 }
@@ -386,6 +2063,94 @@ cast! {
     v: Content => Self(v),
 }
 
+/// The `title-as` parameter of an [`OutlineElem`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum OutlineTitleAs {
+    /// Display the title as a top-level heading.
+    Heading,
+    /// Display the title as a plain block.
+    Content,
+}
+
+/// The `link-target` parameter of an [`OutlineElem`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum OutlineLinkTarget {
+    /// Link to the element the entry refers to.
+    Element,
+    /// Link to the top of the page containing the element.
+    Page,
+}
+
+/// The `title` parameter of an [`OutlineElem`].
+#[derive(Debug, Clone)]
+pub enum OutlineTitle {
+    /// A fixed title.
+    Content(Content),
+    /// A function mapping from a dictionary describing the outline to its
+    /// title.
+    Func(Func),
+}
+
+cast! {
+    OutlineTitle,
+    self => match self {
+        Self::Content(v) => v.into_value(),
+        Self::Func(v) => v.into_value(),
+    },
+    v: Content => Self::Content(v),
+    v: Func => Self::Func(v),
+}
+
+/// The `fill` parameter of an [`OutlineElem`].
+#[derive(Debug, Clone)]
+pub enum OutlineFill {
+    /// The same fill for all entries.
+    Content(Content),
+    /// Fills indexed by an entry's nesting level, where the last one is used
+    /// for all deeper levels.
+    Array(Vec<Option<Content>>),
+    /// A function mapping from an entry's nesting level to its fill.
+    Func(Func),
+    /// A fixed gap instead of a fill.
+    Gap(Length),
+}
+
+impl OutlineFill {
+    /// Resolve the fill for an entry with the given nesting level.
+    fn resolve(
+        &self,
+        vt: &mut Vt,
+        depth: usize,
+        span: Span,
+    ) -> SourceResult<Option<Content>> {
+        Ok(match self {
+            Self::Content(content) => Some(content.clone()),
+            Self::Array(fills) => fills.get(depth).or(fills.last()).cloned().flatten(),
+            Self::Func(func) => func.call_vt(vt, [depth])?.cast().at(span)?,
+            Self::Gap(gap) => Some(HElem::new((*gap).into()).pack()),
+        })
+    }
+}
+
+cast! {
+    OutlineFill,
+    self => match self {
+        Self::Content(v) => v.into_value(),
+        Self::Array(v) => v.into_value(),
+        Self::Func(v) => v.into_value(),
+        Self::Gap(v) => v.into_value(),
+    },
+    v: Content => Self::Content(v),
+    v: Length => Self::Gap(v),
+    array: Array => {
+        if array.is_empty() {
+            bail!("array must contain at least one fill");
+        }
+        Self::Array(array.into_iter().map(Value::cast).collect::<StrResult<_>>()?)
+    },
+    v: Func => Self::Func(v),
+}
+
 /// Represents each entry line in an outline, including the reference to the
 /// outlined element, its page number, and the filler content between both.
 ///
@@ -444,9 +2209,38 @@ pub struct OutlineEntry {
     pub fill: Option<Content>,
 
     /// The page number of the element this entry links to, formatted with the
-    /// numbering set for the referenced page.
+    /// numbering set for the referenced page. This is empty if the outline
+    /// does not display the page number for this entry.
     #[required]
     pub page: Content,
+
+    /// Where the page number is placed, as defined by the outline element this
+    /// entry is located in.
+    #[internal]
+    #[default(HAlign::End)]
+    pub page_position: HAlign,
+
+    /// The indent in front of the body. This is only set when the page number
+    /// is placed at the start of the line. Otherwise, the outline places the
+    /// indent in front of the whole entry.
+    #[internal]
+    pub indent: Content,
+
+    /// The content the body ends with if it is cut off, as defined by the
+    /// outline element this entry is located in.
+    #[internal]
+    pub ellipsis: Option<Content>,
+
+    /// Where the entry links to, as defined by the outline element this entry
+    /// is located in.
+    #[internal]
+    #[default(OutlineLinkTarget::Element)]
+    pub link_target: OutlineLinkTarget,
+
+    /// The column following the page number, as defined by the outline
+    /// element this entry is located in.
+    #[internal]
+    pub annotation: Option<Content>,
 }
 
 impl OutlineEntry {
@@ -458,36 +2252,49 @@ impl OutlineEntry {
         vt: &mut Vt,
         span: Span,
         elem: Content,
-        fill: Option<Content>,
+        location: Location,
+        numbering: &Smart<Option<Numbering>>,
+        page_numbering: &Smart<Numbering>,
+        total: bool,
     ) -> SourceResult<Option<Self>> {
         let Some(outlinable) = elem.with::<dyn Outlinable>() else {
             bail!(span, "cannot outline {}", elem.func().name());
         };
 
-        let Some(body) = outlinable.outline(vt)? else {
-            return Ok(None);
+        let page_numbering = resolve_page_numbering(vt, page_numbering, location);
+
+        let counter = Counter::new(CounterKey::Page);
+        let state = if total || page_numbering.displays_total() {
+            counter.both(vt, location)?
+        } else {
+            counter.at(vt, location)?
         };
+        let page = state.display(vt, &page_numbering)?;
 
-        let location = elem.location().unwrap();
-        let page_numbering = vt
-            .introspector
-            .page_numbering(location)
-            .cast::<Option<Numbering>>()
-            .unwrap()
-            .unwrap_or_else(|| {
-                Numbering::Pattern(NumberingPattern::from_str("1").unwrap())
-            });
+        // The element may want to lay out its page number by itself.
+        let info = OutlinePage { location, numbering: page_numbering, page };
+        if let Some(body) = outlinable.outline_with_page(vt, &info)? {
+            let page = Content::empty();
+            return Ok(Some(Self::new(outlinable.level(), elem, body, None, page)));
+        }
 
-        let page = Counter::new(CounterKey::Page)
-            .at(vt, location)?
-            .display(vt, &page_numbering)?;
+        let body = match numbering {
+            Smart::Auto => outlinable.outline(vt)?,
+            Smart::Custom(numbering) => {
+                outlinable.outline_numbered(vt, numbering.as_ref())?
+            }
+        };
+
+        let Some(body) = body else {
+            return Ok(None);
+        };
 
-        Ok(Some(Self::new(outlinable.level(), elem, body, fill, page)))
+        Ok(Some(Self::new(outlinable.level(), elem, body, None, info.page)))
     }
 }
 
 impl Show for OutlineEntry {
-    fn show(&self, _vt: &mut Vt, _: StyleChain) -> SourceResult<Content> {
+    fn show(&self, vt: &mut Vt, styles: StyleChain) -> SourceResult<Content> {
         let mut seq = vec![];
         let elem = self.element();
 
@@ -497,9 +2304,170 @@ impl Show for OutlineEntry {
         };
 
         // The body text remains overridable.
-        seq.push(self.body().linked(Destination::Location(location)));
+        let body = self.body();
+        let ellipsis = self.ellipsis(styles);
+        let link = match self.link_target(styles) {
+            OutlineLinkTarget::Element => Destination::Location(location),
+            // The page is looked up through the introspector, so it is kept up
+            // to date when the element moves to another page.
+            OutlineLinkTarget::Page => Destination::Position(Position {
+                page: vt.introspector.page(location),
+                point: Point::zero(),
+            }),
+        };
+
+        // The links describe the entry by its text, so that assistive
+        // technology doesn't have to piece it together from the fill.
+        let page = self.page();
+        let mut alt = EcoString::from(body.plain_text().trim());
+        if !page.is_empty() {
+            alt.push(' ');
+            alt.push_str(page.plain_text().trim());
+        }
+
+        // The annotation column ends the line, no matter what precedes it.
+        let annotation = self.annotation(styles);
+        let column = |annotation: Option<Content>| match annotation {
+            Some(column) => HElem::new(Fr::one().into()).pack() + column,
+            None => Content::empty(),
+        };
+
+        // Without a page number, there is nothing to fill up to.
+        if page.is_empty() {
+            let body = clip(body, ellipsis, None).linked_with_alt(link, alt);
+            return Ok(self.indent(styles) + body + column(annotation));
+        }
+
+        let page = page.linked_with_alt(link.clone(), alt.clone());
+
+        // A page number at the start is directly followed by the body.
+        if self.page_position(styles) == HAlign::Start {
+            let body = clip(body, ellipsis, None).linked_with_alt(link, alt);
+            return Ok(page
+                + SpaceElem::new().pack()
+                + self.indent(styles)
+                + body
+                + column(annotation));
+        }
+
+        if let Some(ellipsis) = ellipsis {
+            // The fill is laid out along with the clipped body so that it
+            // starts right where the body ends.
+            let trailing =
+                self.fill().is_some_and(|fill| has_trailing_gap(&fill, styles));
+            seq.push(clip(body, Some(ellipsis), self.fill()).linked_with_alt(link, alt));
+            if !trailing {
+                seq.push(SpaceElem::new().pack());
+            }
+        } else {
+            seq.push(body.linked_with_alt(link, alt));
+
+            // Add filler symbols between the section name and page number. A
+            // bare gap is used as is, without pushing the page number away.
+            if let Some(filler) = self.fill().filter(|filler| filler.is::<HElem>()) {
+                seq.push(filler);
+            } else if let Some(filler) = self.fill() {
+                let trailing = has_trailing_gap(&filler, styles);
+                seq.push(SpaceElem::new().pack());
+                seq.push(
+                    BoxElem::new()
+                        .with_body(Some(filler))
+                        .with_width(Fr::one().into())
+                        .pack(),
+                );
+                if !trailing {
+                    seq.push(SpaceElem::new().pack());
+                }
+            } else {
+                seq.push(HElem::new(Fr::one().into()).pack());
+            }
+        }
+
+        // Add the page number.
+        seq.push(page);
+        if let Some(annotation) = annotation {
+            seq.push(SpaceElem::new().pack());
+            seq.push(annotation);
+        }
+
+        Ok(Content::sequence(seq))
+    }
+}
+
+/// Whether a fill is a repeat that keeps its own gap in front of the page
+/// number, making the separating space redundant.
+fn has_trailing_gap(fill: &Content, styles: StyleChain) -> bool {
+    fill.to::<RepeatElem>()
+        .is_some_and(|repeat| repeat.trailing(styles).is_some())
+}
+
+/// Wraps an entry's body such that it is clipped to the rest of the line if an
+/// ellipsis is given.
+fn clip(body: Content, ellipsis: Option<Content>, fill: Option<Content>) -> Content {
+    let Some(ellipsis) = ellipsis else { return body };
+    BoxElem::new()
+        .with_body(Some(ClippedEntryElem::new(body, ellipsis, fill).pack()))
+        .with_width(Fr::one().into())
+        .pack()
+}
+
+/// An outline entry's body, clipped to a single line.
+///
+/// Must be placed in a box with fractional width to know how much space the
+/// body may take up.
+#[elem(Layout)]
+struct ClippedEntryElem {
+    /// The body to clip.
+    #[required]
+    pub body: Content,
+
+    /// The content to end the body with if it is clipped.
+    #[required]
+    pub ellipsis: Content,
+
+    /// The content to fill the rest of the line with.
+    #[required]
+    pub fill: Option<Content>,
+}
+
+impl Layout for ClippedEntryElem {
+    #[tracing::instrument(name = "ClippedEntryElem::layout", skip_all)]
+    fn layout(
+        &self,
+        vt: &mut Vt,
+        styles: StyleChain,
+        regions: Regions,
+    ) -> SourceResult<Fragment> {
+        let available = regions.size.x;
+        let unbounded = Regions::one(Axes::splat(Abs::inf()), Axes::splat(false));
+
+        let mut body = self.body();
+        if body.measure(vt, styles, unbounded)?.into_frame().width() > available {
+            // Find the longest prefix of the body's text that still fits
+            // along with the ellipsis.
+            let text = body.plain_text();
+            let ellipsis = self.ellipsis();
+            let clipped =
+                |end: usize| TextElem::packed(text[..end].trim_end()) + ellipsis.clone();
+
+            let mut ends = vec![0];
+            ends.extend(text.char_indices().map(|(i, c)| i + c.len_utf8()));
+
+            let (mut lo, mut hi) = (0, ends.len() - 1);
+            while lo < hi {
+                let mid = (lo + hi + 1) / 2;
+                let frame = clipped(ends[mid]).measure(vt, styles, unbounded)?;
+                if frame.into_frame().width() <= available {
+                    lo = mid;
+                } else {
+                    hi = mid - 1;
+                }
+            }
 
-        // Add filler symbols between the section name and page number.
+            body = clipped(ends[lo]);
+        }
+
+        let mut seq = vec![body];
         if let Some(filler) = self.fill() {
             seq.push(SpaceElem::new().pack());
             seq.push(
@@ -508,15 +2476,9 @@ impl Show for OutlineEntry {
                     .with_width(Fr::one().into())
                     .pack(),
             );
-            seq.push(SpaceElem::new().pack());
-        } else {
-            seq.push(HElem::new(Fr::one().into()).pack());
         }
 
-        // Add the page number.
-        let page = self.page().linked(Destination::Location(location));
-        seq.push(page);
-
-        Ok(Content::sequence(seq))
+        let pod = Regions::one(regions.size, Axes::new(true, false));
+        Content::sequence(seq).layout(vt, styles, pod)
     }
 }
@@ -2,7 +2,7 @@ use super::{BibliographyElem, CiteElem, Counter, Figurable, Numbering};
 use crate::math::EquationElem;
 use crate::meta::FootnoteElem;
 use crate::prelude::*;
-use crate::text::TextElem;
+use crate::text::{QuoteElem, TextElem};
 
 /// A reference to a label or bibliography.
 ///
@@ -177,6 +177,11 @@ impl Show for RefElem {
                 })
                 .at(span)?;
 
+            // Quotes are never numbered, so there is no numbering to enable.
+            if elem.func() == QuoteElem::elem() {
+                bail!(span, "cannot reference quote without numbering");
+            }
+
             let numbering = refable
                 .numbering()
                 .ok_or_else(|| {
@@ -196,10 +201,10 @@ impl Show for RefElem {
                 ))
                 .at(span)?;
 
-            let numbers = refable
-                .counter()
+            let counter = refable.counter();
+            let numbers = counter
                 .at(vt, elem.location().unwrap())?
-                .display(vt, &numbering.trimmed())?;
+                .display(vt, &numbering.trimmed().for_counter(&counter))?;
 
             let supplement = match self.supplement(styles) {
                 Smart::Auto => refable.supplement(),
@@ -18,6 +18,10 @@ pub trait ContentExt {
     /// Link the content somewhere.
     fn linked(self, dest: Destination) -> Self;
 
+    /// Link the content somewhere and describe the link with the given text
+    /// for assistive technology.
+    fn linked_with_alt(self, dest: Destination, alt: EcoString) -> Self;
+
     /// Make the content linkable by `.linked(Destination::Location(loc))`.
     ///
     /// Should be used in combination with [`Location::variant`].
@@ -47,7 +51,11 @@ impl ContentExt for Content {
     }
 
     fn linked(self, dest: Destination) -> Self {
-        self.styled(MetaElem::set_data(vec![Meta::Link(dest)]))
+        self.styled(MetaElem::set_data(vec![Meta::Link(dest, None)]))
+    }
+
+    fn linked_with_alt(self, dest: Destination, alt: EcoString) -> Self {
+        self.styled(MetaElem::set_data(vec![Meta::Link(dest, Some(alt))]))
     }
 
     fn backlinked(self, loc: Location) -> Self {
@@ -1,6 +1,9 @@
 use super::{SmartquoteElem, SpaceElem, TextElem};
 use crate::layout::{BlockElem, HElem, PadElem, Spacing, VElem};
-use crate::meta::{BibliographyElem, BibliographyStyle, CiteElem};
+use crate::meta::{
+    BibliographyElem, BibliographyStyle, CiteElem, Counter, Numbering, Outlinable,
+    Refable,
+};
 use crate::prelude::*;
 
 /// Displays a quote alongside it's author.
@@ -33,7 +36,14 @@ use crate::prelude::*;
 ///   flame of Udûn. Go back to the Shadow! You cannot pass.
 /// ]
 /// ```
-#[elem(Finalize, Show)]
+///
+/// Quotes can also be listed in an [outline]($outline):
+/// ```example
+/// #outline(title: [Quotations], target: quote)
+///
+/// #quote(attribution: [René Descartes])[cogito, ergo sum]
+/// ```
+#[elem(Locatable, Finalize, Show, Refable, Outlinable)]
 pub struct QuoteElem {
     /// Whether this is a block quote.
     ///
@@ -207,3 +217,33 @@ impl Finalize for QuoteElem {
             .styled(BlockElem::set_below(VElem::block_around(below)))
     }
 }
+
+impl Refable for QuoteElem {
+    fn supplement(&self) -> Content {
+        Content::empty()
+    }
+
+    fn counter(&self) -> Counter {
+        Counter::of(Self::elem())
+    }
+
+    fn numbering(&self) -> Option<Numbering> {
+        None
+    }
+}
+
+impl Outlinable for QuoteElem {
+    fn outline(&self, _: &mut Vt) -> SourceResult<Option<Content>> {
+        let mut body = self.body();
+        if let Some(Attribution::Content(attribution)) =
+            self.attribution(StyleChain::default())
+        {
+            body += TextElem::packed(" — ") + attribution;
+        }
+        Ok(Some(body))
+    }
+
+    fn participates_in_hierarchy(&self) -> bool {
+        false
+    }
+}
@@ -1409,6 +1409,8 @@ pub enum BinOp {
     In,
     /// The inversed containment operator: `not in`.
     NotIn,
+    /// The coalescing operator: `??`.
+    Coalesce,
     /// The add-assign operator: `+=`.
     AddAssign,
     /// The subtract-assign oeprator: `-=`.
@@ -1437,6 +1439,7 @@ impl BinOp {
             SyntaxKind::GtEq => Self::Geq,
             SyntaxKind::Eq => Self::Assign,
             SyntaxKind::In => Self::In,
+            SyntaxKind::QuestQuest => Self::Coalesce,
             SyntaxKind::PlusEq => Self::AddAssign,
             SyntaxKind::HyphEq => Self::SubAssign,
             SyntaxKind::StarEq => Self::MulAssign,
@@ -1462,6 +1465,7 @@ impl BinOp {
             Self::NotIn => 4,
             Self::And => 3,
             Self::Or => 2,
+            Self::Coalesce => 2,
             Self::Assign => 1,
             Self::AddAssign => 1,
             Self::SubAssign => 1,
@@ -1487,6 +1491,7 @@ impl BinOp {
             Self::Geq => Assoc::Left,
             Self::In => Assoc::Left,
             Self::NotIn => Assoc::Left,
+            Self::Coalesce => Assoc::Left,
             Self::Assign => Assoc::Right,
             Self::AddAssign => Assoc::Right,
             Self::SubAssign => Assoc::Right,
@@ -1512,6 +1517,7 @@ impl BinOp {
             Self::Geq => ">=",
             Self::In => "in",
             Self::NotIn => "not in",
+            Self::Coalesce => "??",
             Self::Assign => "=",
             Self::AddAssign => "+=",
             Self::SubAssign => "-=",
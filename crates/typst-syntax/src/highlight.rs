@@ -216,6 +216,7 @@ pub fn highlight(node: &LinkedNode) -> Option<Tag> {
         SyntaxKind::HyphEq => Some(Tag::Operator),
         SyntaxKind::StarEq => Some(Tag::Operator),
         SyntaxKind::SlashEq => Some(Tag::Operator),
+        SyntaxKind::QuestQuest => Some(Tag::Operator),
         SyntaxKind::Dots => Some(Tag::Operator),
         SyntaxKind::Arrow => Some(Tag::Operator),
         SyntaxKind::Root => Some(Tag::MathOperator),
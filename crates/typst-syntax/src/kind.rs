@@ -136,6 +136,8 @@ pub enum SyntaxKind {
     StarEq,
     /// The divide-assign operator: `/=`.
     SlashEq,
+    /// The coalescing operator: `??`.
+    QuestQuest,
     /// The spread operator: `..`.
     Dots,
     /// An arrow between a closure's parameters and body: `=>`.
@@ -415,6 +417,7 @@ impl SyntaxKind {
             Self::HyphEq => "subtract-assign operator",
             Self::StarEq => "multiply-assign operator",
             Self::SlashEq => "divide-assign operator",
+            Self::QuestQuest => "coalescing operator",
             Self::Dots => "dots",
             Self::Arrow => "arrow",
             Self::Root => "root",
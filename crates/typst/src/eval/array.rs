@@ -8,7 +8,7 @@ use serde::{Deserialize, Serialize};
 
 use super::{
     cast, func, ops, scope, ty, Args, Bytes, CastInfo, FromValue, Func, IntoValue,
-    Reflect, Repr, Value, Version, Vm,
+    NoneValue, Reflect, Repr, Value, Version, Vm,
 };
 use crate::diag::{At, SourceResult, StrResult};
 use crate::eval::ops::{add, mul};
@@ -627,6 +627,20 @@ impl Array {
         flat.into()
     }
 
+    /// Return a new array with all `{none}` items removed. Nested arrays are
+    /// kept as they are.
+    ///
+    /// ```example
+    /// #(1, none, 2, (none,)).compact()
+    /// ```
+    #[func]
+    pub fn compact(&self) -> Array {
+        self.iter()
+            .filter(|item| !NoneValue::castable(item))
+            .cloned()
+            .collect()
+    }
+
     /// Return a new array with the same items, but in reverse order.
     #[func(title = "Reverse")]
     pub fn rev(&self) -> Array {
@@ -647,6 +661,16 @@ impl Array {
     }
 
     /// Combine all items in the array into one.
+    ///
+    /// The items are joined the same way as the results of the expressions in
+    /// a [code block]($scripting/#blocks). In particular, `{none}` joins with
+    /// any value to yield the other value, so that content can be assembled
+    /// conditionally. An empty array yields `{none}`.
+    ///
+    /// ```example
+    /// #let draft = false
+    /// #([*Report*], if draft [ (Draft)], [ by Jane]).join()
+    /// ```
     #[func]
     pub fn join(
         &self,
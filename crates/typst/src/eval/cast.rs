@@ -236,16 +236,24 @@ impl CastInfo {
         let mut matching_type = false;
         let mut parts = vec![];
 
-        self.walk(|info| match info {
-            CastInfo::Any => parts.push("anything".into()),
-            CastInfo::Value(value, _) => {
-                parts.push(value.repr());
-                if value.ty() == found.ty() {
-                    matching_type = true;
+        self.walk(|info| {
+            let part = match info {
+                CastInfo::Any => "anything".into(),
+                CastInfo::Value(value, _) => {
+                    if value.ty() == found.ty() {
+                        matching_type = true;
+                    }
+                    value.repr()
                 }
+                CastInfo::Type(ty) => eco_format!("{ty}"),
+                CastInfo::Union(_) => return,
+            };
+
+            // Nested unions, e.g. from nested options, may contain the same
+            // info several times.
+            if !parts.contains(&part) {
+                parts.push(part);
             }
-            CastInfo::Type(ty) => parts.push(eco_format!("{ty}")),
-            CastInfo::Union(_) => {}
         });
 
         let mut msg = String::from("expected ");
@@ -316,6 +324,7 @@ impl Add for CastInfo {
                 }
                 rhs
             }
+            (lhs, rhs) if lhs == rhs => vec![lhs],
             (lhs, rhs) => vec![lhs, rhs],
         })
     }
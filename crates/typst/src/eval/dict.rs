@@ -7,7 +7,7 @@ use ecow::{eco_format, EcoString};
 use indexmap::IndexMap;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use super::{array, func, scope, ty, Array, Repr, Str, Value};
+use super::{array, func, scope, ty, Array, NoneValue, Reflect, Repr, Str, Value};
 use crate::diag::StrResult;
 use crate::syntax::is_ident;
 use crate::util::{pretty_array_like, separated_list, ArcExt};
@@ -194,6 +194,19 @@ impl Dict {
         self.0.values().cloned().collect()
     }
 
+    /// Returns the dictionary without the pairs whose value is `{none}`.
+    ///
+    /// ```example
+    /// #(a: 1, b: none, c: 2).compact()
+    /// ```
+    #[func]
+    pub fn compact(&self) -> Dict {
+        self.iter()
+            .filter(|(_, value)| !NoneValue::castable(value))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+
     /// Returns the keys and values of the dictionary as an array of pairs. Each
     /// pair is represented as an array of length two.
     #[func]
@@ -191,6 +191,39 @@ impl Func {
         self.params()?.iter().find(|param| param.name == name)
     }
 
+    /// The number of positional arguments the function accepts.
+    ///
+    /// Returns `None` if the function accepts arbitrarily many positional
+    /// arguments through a variadic parameter or an argument sink.
+    pub fn arity(&self) -> Option<usize> {
+        match &self.repr {
+            Repr::Native(_) | Repr::Element(_) => {
+                let params = self.params()?;
+                if params.iter().any(|param| param.variadic) {
+                    return None;
+                }
+                Some(params.iter().filter(|param| param.positional).count())
+            }
+            Repr::Closure(closure) => closure.arity(),
+            Repr::With(with) => {
+                let applied = with.1.items.iter().filter(|arg| arg.name.is_none());
+                let applied = applied.count();
+                with.0.arity().map(|arity| arity.saturating_sub(applied))
+            }
+        }
+    }
+
+    /// Whether the function has a named parameter with the given name.
+    pub fn has_named_param(&self, name: &str) -> bool {
+        match &self.repr {
+            Repr::Native(_) | Repr::Element(_) => {
+                self.param(name).is_some_and(|param| param.named)
+            }
+            Repr::Closure(closure) => closure.has_named_param(name),
+            Repr::With(with) => with.0.has_named_param(name),
+        }
+    }
+
     /// Get details about the function's return type.
     pub fn returns(&self) -> Option<&'static CastInfo> {
         static CONTENT: Lazy<CastInfo> =
@@ -485,6 +518,28 @@ impl Closure {
             .map(|ident| ident.as_str())
     }
 
+    /// The number of positional parameters, if there is no argument sink.
+    pub fn arity(&self) -> Option<usize> {
+        let closure = self.node.cast::<ast::Closure>().unwrap();
+        let mut arity = 0;
+        for param in closure.params().children() {
+            match param {
+                ast::Param::Pos(_) => arity += 1,
+                ast::Param::Named(_) => {}
+                ast::Param::Sink(_) => return None,
+            }
+        }
+        Some(arity)
+    }
+
+    /// Whether the closure has a named parameter with the given name.
+    pub fn has_named_param(&self, name: &str) -> bool {
+        let closure = self.node.cast::<ast::Closure>().unwrap();
+        closure.params().children().any(|param| {
+            matches!(param, ast::Param::Named(named) if named.name().as_str() == name)
+        })
+    }
+
     /// Call the function in the context with the arguments.
     #[comemo::memoize]
     #[tracing::instrument(skip_all)]
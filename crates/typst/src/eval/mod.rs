@@ -1067,6 +1067,7 @@ impl Eval for ast::Binary<'_> {
             ast::BinOp::Geq => apply_binary_expr(self, vm, ops::geq),
             ast::BinOp::In => apply_binary_expr(self, vm, ops::in_),
             ast::BinOp::NotIn => apply_binary_expr(self, vm, ops::not_in),
+            ast::BinOp::Coalesce => apply_binary_expr(self, vm, |_, b| Ok(b)),
             ast::BinOp::Assign => apply_assignment(self, vm, |_, b| Ok(b)),
             ast::BinOp::AddAssign => apply_assignment(self, vm, ops::add),
             ast::BinOp::SubAssign => apply_assignment(self, vm, ops::sub),
@@ -1084,9 +1085,10 @@ fn apply_binary_expr(
 ) -> SourceResult<Value> {
     let lhs = binary.lhs().eval(vm)?;
 
-    // Short-circuit boolean operations.
+    // Short-circuit boolean and coalescing operations.
     if (binary.op() == ast::BinOp::And && lhs == false.into_value())
         || (binary.op() == ast::BinOp::Or && lhs == true.into_value())
+        || (binary.op() == ast::BinOp::Coalesce && lhs != Value::None)
     {
         return Ok(lhs);
     }
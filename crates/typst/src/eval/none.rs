@@ -104,3 +104,21 @@ impl<T: FromValue> FromValue for Option<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geom::Length;
+
+    #[track_caller]
+    fn test<T: FromValue + Debug>(value: impl IntoValue, exp: &str) {
+        assert_eq!(T::from_value(value.into_value()).unwrap_err().as_str(), exp);
+    }
+
+    #[test]
+    fn test_option_cast_error() {
+        test::<Option<Length>>("hi", "expected length or none, found string");
+        test::<Option<Option<i64>>>("hi", "expected integer or none, found string");
+        test::<Option<NoneValue>>("hi", "expected none, found string");
+    }
+}
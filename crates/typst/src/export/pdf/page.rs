@@ -5,7 +5,7 @@ use pdf_writer::types::{
     ActionType, AnnotationType, ColorSpaceOperand, LineCapStyle, LineJoinStyle,
     NumberingStyle,
 };
-use pdf_writer::{Content, Filter, Finish, Name, Rect, Ref, Str};
+use pdf_writer::{Content, Filter, Finish, Name, Rect, Ref, Str, TextStr};
 
 use super::color::PaintEncode;
 use super::extg::ExtGState;
@@ -152,10 +152,13 @@ fn write_page(ctx: &mut PdfContext, i: usize) {
     }
 
     let mut annotations = page_writer.annotations();
-    for (dest, rect) in &page.links {
+    for (dest, alt, rect) in &page.links {
         let mut annotation = annotations.push();
         annotation.subtype(AnnotationType::Link).rect(*rect);
         annotation.border(0.0, 0.0, 0.0, None);
+        if let Some(alt) = alt {
+            annotation.contents(TextStr(alt));
+        }
 
         let pos = match dest {
             Destination::Url(uri) => {
@@ -198,8 +201,9 @@ pub struct Page {
     pub content: Vec<u8>,
     /// Whether the page uses opacities.
     pub uses_opacities: bool,
-    /// Links in the PDF coordinate system.
-    pub links: Vec<(Destination, Rect)>,
+    /// Links in the PDF coordinate system, along with their alternative
+    /// descriptions.
+    pub links: Vec<(Destination, Option<EcoString>, Rect)>,
     /// The page's PDF label.
     pub label: Option<PdfPageLabel>,
 }
@@ -214,7 +218,7 @@ pub struct PageContext<'a, 'b> {
     saves: Vec<State>,
     bottom: f32,
     uses_opacities: bool,
-    links: Vec<(Destination, Rect)>,
+    links: Vec<(Destination, Option<EcoString>, Rect)>,
 }
 
 /// A simulated graphics state used to deduplicate graphics state changes and
@@ -440,7 +444,7 @@ fn write_frame(ctx: &mut PageContext, frame: &Frame) {
             FrameItem::Shape(shape, _) => write_shape(ctx, pos, shape),
             FrameItem::Image(image, size, _) => write_image(ctx, x, y, image, *size),
             FrameItem::Meta(meta, size) => match meta {
-                Meta::Link(dest) => write_link(ctx, pos, dest, *size),
+                Meta::Link(dest, alt) => write_link(ctx, pos, dest, alt, *size),
                 Meta::Elem(_) => {}
                 Meta::Hide => {}
                 Meta::PageNumbering(_) => {}
@@ -644,7 +648,13 @@ fn write_image(ctx: &mut PageContext, x: f32, y: f32, image: &Image, size: Size)
 }
 
 /// Save a link for later writing in the annotations dictionary.
-fn write_link(ctx: &mut PageContext, pos: Point, dest: &Destination, size: Size) {
+fn write_link(
+    ctx: &mut PageContext,
+    pos: Point,
+    dest: &Destination,
+    alt: &Option<EcoString>,
+    size: Size,
+) {
     let mut min_x = Abs::inf();
     let mut min_y = Abs::inf();
     let mut max_x = -Abs::inf();
@@ -670,7 +680,7 @@ fn write_link(ctx: &mut PageContext, pos: Point, dest: &Destination, size: Size)
     let y2 = min_y.to_f32();
     let rect = Rect::new(x1, y1, x2, y2);
 
-    ctx.links.push((dest.clone(), rect));
+    ctx.links.push((dest.clone(), alt.clone(), rect));
 }
 
 impl From<&LineCap> for LineCapStyle {
@@ -743,3 +753,35 @@ impl From<PdfPageLabelStyle> for NumberingStyle {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::doc::Document;
+
+    fn export_link(alt: Option<EcoString>) -> Vec<u8> {
+        let size = Size::splat(Abs::pt(20.0));
+        let mut frame = Frame::soft(size);
+        let dest = Destination::Url("https://typst.app".into());
+        frame.push(Point::zero(), FrameItem::Meta(Meta::Link(dest, alt), size));
+        let document = Document { pages: vec![frame], ..Default::default() };
+        crate::export::pdf(&document)
+    }
+
+    fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+        haystack.windows(needle.len()).any(|window| window == needle)
+    }
+
+    #[test]
+    fn test_link_alt_is_written_as_contents() {
+        let pdf = export_link(Some("Introduction 1".into()));
+        assert!(contains(&pdf, b"/Contents (Introduction 1)"));
+    }
+
+    #[test]
+    fn test_link_without_alt_has_no_contents() {
+        let pdf = export_link(None);
+        assert!(contains(&pdf, b"/Subtype /Link"));
+        assert!(!contains(&pdf, b"/Contents ("));
+    }
+}
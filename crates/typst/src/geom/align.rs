@@ -14,6 +14,15 @@ use super::*;
 /// - `horizon`: Aligns in the middle, vertically.
 /// - `bottom`: Align at the bottom.
 ///
+/// A [ratio]($ratio) between `{0%}` and `{100%}` aligns horizontally at that
+/// fraction of the free space, measured from the start of the text direction:
+/// `{0%}`, `{50%}`, and `{100%}` behave like `start`, `center`, and `end`,
+/// respectively.
+///
+/// ```example
+/// #align(25%)[A quarter across]
+/// ```
+///
 /// These values are available globally and also in the alignment type's scope,
 /// so you can write either of the following two:
 ///
@@ -32,6 +41,27 @@ use super::*;
 /// #align(center + bottom)[Hi]
 /// ```
 ///
+/// A single `center` only centers horizontally. As a shorthand for
+/// `center + horizon`, you can write `center + center` to center along both
+/// axes.
+///
+/// ```example
+/// #set page(height: 3cm)
+/// #align(center + center)[Hi]
+/// ```
+///
+/// Alternatively, you can pass a dictionary with an `x` and a `y` key for the
+/// horizontal and vertical component, respectively. Each of them may be left
+/// out or be `{none}`. This is useful when the alignment is computed.
+///
+/// ```example
+/// #let corner = (x: right, y: top)
+/// #align(corner)[Hi]
+/// ```
+///
+/// This is also how a ratio is combined with a vertical alignment, as in
+/// `{(x: 25%, y: bottom)}`.
+///
 /// # Fields
 /// The `x` and `y` fields hold the alignment's horizontal and vertical
 /// components, respectively (as yet another `alignment`). They may be `{none}`.
@@ -113,7 +143,7 @@ impl Align {
     /// #(left + bottom).inv()
     /// ```
     #[func(title = "Inverse")]
-    pub const fn inv(self) -> Align {
+    pub fn inv(self) -> Align {
         match self {
             Self::H(h) => Self::H(h.inv()),
             Self::V(v) => Self::V(v.inv()),
@@ -134,6 +164,9 @@ impl Add for Align {
     fn add(self, rhs: Self) -> Self::Output {
         match (self, rhs) {
             (Self::H(x), Self::V(y)) | (Self::V(y), Self::H(x)) => Ok(x + y),
+            (Self::H(HAlign::Center), Self::H(HAlign::Center)) => {
+                Ok(HAlign::Center + VAlign::Horizon)
+            }
             (Self::H(_), Self::H(_)) => bail!("cannot add two horizontal alignments"),
             (Self::V(_), Self::V(_)) => bail!("cannot add two vertical alignments"),
             (Self::H(_), Self::Both(..)) | (Self::Both(..), Self::H(_)) => {
@@ -192,6 +225,23 @@ impl From<Side> for Align {
 
 cast! {
     type Align,
+    ratio: Ratio => {
+        if ratio < Ratio::zero() || ratio > Ratio::one() {
+            bail!("ratio alignment must be between 0% and 100%");
+        }
+        Self::H(HAlign::Ratio(ratio))
+    },
+    mut dict: Dict => {
+        let x = dict.take("x").ok().map(Value::cast::<Option<HAlign>>).transpose()?;
+        let y = dict.take("y").ok().map(Value::cast::<Option<VAlign>>).transpose()?;
+        dict.finish(&["x", "y"])?;
+        match (x.flatten(), y.flatten()) {
+            (Some(x), Some(y)) => x + y,
+            (Some(x), None) => Self::H(x),
+            (None, Some(y)) => Self::V(y),
+            (None, None) => bail!("alignment dictionary must specify at least one axis"),
+        }
+    },
 }
 
 /// Where to align something horizontally.
@@ -203,28 +253,33 @@ pub enum HAlign {
     Center,
     Right,
     End,
+    /// At a fraction of the free space, measured from the start.
+    Ratio(Ratio),
 }
 
 impl HAlign {
     /// The inverse horizontal alignment.
-    pub const fn inv(self) -> Self {
+    pub fn inv(self) -> Self {
         match self {
             Self::Start => Self::End,
             Self::Left => Self::Right,
             Self::Center => Self::Center,
             Self::Right => Self::Left,
             Self::End => Self::Start,
+            Self::Ratio(ratio) => Self::Ratio(Ratio::one() - ratio),
         }
     }
 
     /// Resolve the axis alignment based on the horizontal direction.
-    pub const fn fix(self, dir: Dir) -> FixedAlign {
+    pub fn fix(self, dir: Dir) -> FixedAlign {
         match (self, dir.is_positive()) {
             (Self::Start, true) | (Self::End, false) => FixedAlign::Start,
             (Self::Left, _) => FixedAlign::Start,
             (Self::Center, _) => FixedAlign::Center,
             (Self::Right, _) => FixedAlign::End,
             (Self::End, true) | (Self::Start, false) => FixedAlign::End,
+            (Self::Ratio(ratio), true) => FixedAlign::Ratio(ratio),
+            (Self::Ratio(ratio), false) => FixedAlign::Ratio(Ratio::one() - ratio),
         }
     }
 }
@@ -237,6 +292,7 @@ impl Repr for HAlign {
             Self::Center => "center".into(),
             Self::Right => "right".into(),
             Self::End => "end".into(),
+            Self::Ratio(ratio) => ratio.repr(),
         }
     }
 }
@@ -337,12 +393,15 @@ cast! {
 /// A fixed alignment in the global coordinate space.
 ///
 /// For horizontal alignment, start is globally left and for vertical alignment
-/// it is globally top.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+/// it is globally top. Alignments are ordered by their position, so ratios sort
+/// in between the other variants.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum FixedAlign {
     Start,
     Center,
     End,
+    /// At a fraction of the extent, measured from the start.
+    Ratio(Ratio),
 }
 
 impl FixedAlign {
@@ -353,10 +412,38 @@ impl FixedAlign {
             Self::Start => Abs::zero(),
             Self::Center => extent / 2.0,
             Self::End => extent,
+            Self::Ratio(ratio) => ratio.of(extent),
+        }
+    }
+
+    /// The fraction of the extent at which this alignment is positioned.
+    fn fraction(self) -> Ratio {
+        match self {
+            Self::Start => Ratio::zero(),
+            Self::Center => Ratio::new(0.5),
+            Self::End => Ratio::one(),
+            Self::Ratio(ratio) => ratio,
         }
     }
 }
 
+impl PartialOrd for FixedAlign {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FixedAlign {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Break ties between a keyword and an equally positioned ratio, so
+        // that the order stays consistent with equality.
+        let is_ratio = |align: &Self| matches!(align, Self::Ratio(_));
+        self.fraction()
+            .cmp(&other.fraction())
+            .then_with(|| is_ratio(self).cmp(&is_ratio(other)))
+    }
+}
+
 impl From<Side> for FixedAlign {
     fn from(side: Side) -> Self {
         match side {
@@ -9,6 +9,26 @@ use typst::World;
 use unscanny::Scanner;
 
 const TEXT: &str = include_str!("../typ/compiler/bench.typ");
+const HEADINGS: &str = r#"
+#set heading(numbering: "1.1")
+#outline()
+#for i in range(250) [
+  = Chapter
+  == Section
+]
+"#;
+const OUTLINES: &str = r#"
+#set heading(numbering: "1.1")
+#outline()
+#outline(target: figure.where(kind: image))
+#outline(target: figure.where(kind: table))
+#for i in range(100) [
+  = Chapter
+  #figure(rect(), caption: [Image])
+  == Section
+  #figure(table[A], caption: [Table])
+]
+"#;
 const FONT: &[u8] = include_bytes!("../../assets/fonts/LinLibertine_R.ttf");
 
 main!(
@@ -19,6 +39,8 @@ main!(
     bench_eval,
     bench_typeset,
     bench_compile,
+    bench_headings,
+    bench_outlines,
     bench_render,
 );
 
@@ -86,6 +108,18 @@ fn bench_compile(iai: &mut Iai) {
     iai.run(|| typst::compile(&world, &mut tracer));
 }
 
+fn bench_headings(iai: &mut Iai) {
+    let world = BenchWorld::with_source(HEADINGS);
+    let mut tracer = Tracer::new();
+    iai.run(|| typst::compile(&world, &mut tracer));
+}
+
+fn bench_outlines(iai: &mut Iai) {
+    let world = BenchWorld::with_source(OUTLINES);
+    let mut tracer = Tracer::new();
+    iai.run(|| typst::compile(&world, &mut tracer));
+}
+
 fn bench_render(iai: &mut Iai) {
     let world = BenchWorld::new();
     let mut tracer = Tracer::new();
@@ -102,6 +136,10 @@ struct BenchWorld {
 
 impl BenchWorld {
     fn new() -> Self {
+        Self::with_source(TEXT)
+    }
+
+    fn with_source(text: &str) -> Self {
         let font = Font::new(FONT.into(), 0).unwrap();
         let book = FontBook::from_fonts([&font]);
 
@@ -109,7 +147,7 @@ impl BenchWorld {
             library: Prehashed::new(typst_library::build()),
             book: Prehashed::new(book),
             font,
-            source: Source::detached(TEXT),
+            source: Source::detached(text),
         }
     }
 
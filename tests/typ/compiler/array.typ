@@ -205,6 +205,14 @@
 // Error: 2-14 cannot calculate product of empty array with no default
 #().product()
 
+---
+// Test the `compact` method.
+#test(().compact(), ())
+#test((none, none).compact(), ())
+#test((1, none, "a", none).compact(), (1, "a"))
+#test((1, (none, 2), none).compact(), (1, (none, 2)))
+#test(((none,), none).compact(), ((none,),))
+
 ---
 // Test the `rev` method.
 #test(range(3).rev(), (2, 1, 0))
@@ -216,6 +224,15 @@
 #test(("a", "b", "c").join(), "abc")
 #test("(" + ("a", "b", "c").join(", ") + ")", "(a, b, c)")
 
+---
+// Test joining with none, which yields the other value.
+#test((none,).join(), none)
+#test((none, [a], none, [b]).join(), [a] + [b])
+#test(("a", none, "b").join(), "ab")
+#test(([a], "b").join(), [a] + [b])
+#test((none, (1,), (2,)).join(), (1, 2))
+#test(("a", none, "c").join(", "), "a, , c")
+
 ---
 // Error: 2-22 cannot join boolean with boolean
 #(true, false).join()
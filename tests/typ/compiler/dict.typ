@@ -68,6 +68,9 @@
 #test(dict.values(), (3, 2, 1))
 #test(dict.pairs().map(p => p.first() + str(p.last())).join(), "a3c2b1")
 
+#test((a: none, b: 1, c: none).compact(), (b: 1))
+#test((:).compact(), (:))
+
 #dict.remove("c")
 #test("c" in dict, false)
 #test(dict, (a: 3, b: 1))
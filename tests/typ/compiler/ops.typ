@@ -165,6 +165,22 @@
 #test(false and dont-care, false)
 #test(true or dont-care, true)
 
+---
+// Test the coalescing operator.
+#test(none ?? 1, 1)
+#test(2 ?? 1, 2)
+#test(false ?? true, false)
+#test(none ?? none, none)
+#test(none ?? none ?? "a", "a")
+#test(1 + 1 ?? 3, 2)
+#test((x: none).x ?? 0, 0)
+
+// Short-circuiting.
+#test(1 ?? dont-care, 1)
+
+// Error: 11-20 unknown variable: dont-care
+#(none ?? dont-care)
+
 ---
 // Test equality operators.
 
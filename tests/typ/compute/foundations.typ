@@ -130,3 +130,11 @@ _No relative giraffe!_
 ---
 // Error: 7-12 expected semicolon or line break
 #eval("1 2")
+
+---
+// Test coalesce.
+#test(coalesce(), none)
+#test(coalesce(none, none), none)
+#test(coalesce(none, 1, 2), 1)
+#test(coalesce(false, none), false)
+#test(coalesce((a: none).a, "b"), "b")
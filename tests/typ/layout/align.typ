@@ -39,6 +39,14 @@
 #test(type(horizon), alignment)
 #test(type(center + horizon), alignment)
 
+---
+// Ref: false
+// A single center is horizontal, while two of them center along both axes.
+#test(center.x, center)
+#test(center.y, none)
+#test(center + center, center + horizon)
+#test((center + center).y, horizon)
+
 ---
 // Error: 8-22 cannot add two horizontal alignments
 #align(center + right, [A])
@@ -50,3 +58,105 @@
 ---
 // Error: 8-30 cannot add a vertical and a 2D alignment
 #align(top + (bottom + right), [A])
+
+---
+// Ref: false
+// Content is padded before it is aligned.
+#set page(width: 100pt, margin: 0pt)
+#let end = box[#metadata(none)<end>]
+#align(right, end)
+#align(right, pad: (right: 10pt), end)
+#align(center, pad: (left: 20pt), end)
+
+#locate(loc => {
+  let xs = query(<end>, loc).map(it => it.location().position().x / 1pt)
+  test(xs, (100, 90, 60))
+})
+
+---
+// Ref: false
+// The current alignment can be retrieved.
+#style(styles => test(align.current(styles), left + top))
+#align(right, style(styles => test(align.current(styles), right + top)))
+#align(center + bottom, style(styles => {
+  test(align.current(styles), center + bottom)
+}))
+
+#set text(dir: rtl)
+#align(end, style(styles => test(align.current(styles), left + top)))
+
+---
+// Ref: false
+// An alignment can be given as a dictionary with one or both axes.
+#let roundtrip(it) = align((x: it.x, y: it.y))[].alignment
+#test(roundtrip(right + top), right + top)
+#test(roundtrip(center), center)
+#test(roundtrip(bottom), bottom)
+#test(align((y: horizon, x: start))[].alignment, start + horizon)
+
+#set text(dir: rtl)
+#align((x: end, y: bottom), style(styles => {
+  test(align.current(styles), left + bottom)
+}))
+
+---
+// Error: 8-16 expected `start`, `left`, `center`, `right`, or `end`, found top
+#align((x: top))[A]
+
+---
+// Error: 8-25 unexpected key "z", valid keys are "x" and "y"
+#align((x: left, z: top))[A]
+
+---
+// Error: 8-11 alignment dictionary must specify at least one axis
+#align((:))[A]
+
+---
+// Ref: false
+// A ratio aligns at that fraction of the free space, measured from the start.
+#set page(width: 100pt, margin: 0pt)
+#let end = box[#metadata(none)<end>]
+#align(0%, end)
+#align(left, end)
+#align(50%, end)
+#align(center, end)
+#align(100%, end)
+#align(right, end)
+#align(25%, end)
+#[
+  #set text(dir: rtl)
+  #align(25%, end)
+]
+
+#locate(loc => {
+  let xs = query(<end>, loc).map(it => it.location().position().x / 1pt)
+  test(xs, (0, 0, 50, 50, 100, 100, 25, 75))
+})
+
+---
+// Ref: false
+// A ratio combines with a vertical alignment through a dictionary.
+#let it = align((x: 25%, y: bottom))[].alignment
+#test(it.x, align(25%)[].alignment)
+#test(it.y, bottom)
+#align(25%, style(styles => {
+  test(align.current(styles), align((x: 25%, y: top))[].alignment)
+}))
+
+// The ratio stays relative to the start in right-to-left text.
+#set text(dir: rtl)
+#align(25%, style(styles => {
+  test(align.current(styles), align((x: 25%, y: top))[].alignment)
+}))
+
+---
+// Error: 8-12 ratio alignment must be between 0% and 100%
+#align(-50%)[A]
+
+---
+// Error: 8-12 ratio alignment must be between 0% and 100%
+#align(250%)[A]
+
+---
+// Error: 8-25 ratio alignment must be between 0% and 100%
+#align((x: 120%, y: top))[A]
@@ -48,3 +48,43 @@ First!
   But, soft! what light through yonder window breaks? It is the east, and Juliet
   is the sun.
 ]
+
+---
+// Ref: false
+// A fractional box stops growing at its maximum width.
+#set page(width: 100pt, margin: 0pt)
+#let end = box[#metadata(none)<end>]
+#let capped(max) = box(width: 1fr, max-width: max, align(right, end))
+
+#capped(none)
+
+#capped(20pt)
+
+#capped(200pt)
+
+A #capped(1cm) B
+
+#locate(loc => {
+  let xs = query(<end>, loc).map(it => it.location().position().x)
+  let xs = xs.map(x => calc.round(x / 1pt, digits: 2))
+  test(xs.slice(0, 3), (100, 20, 100))
+  test(xs.at(3) > 28.35 and xs.at(3) < 50, true)
+})
+
+---
+// Ref: false
+// A block aligns its content without adding any vertical space.
+#set page(width: 100pt, height: auto, margin: 0pt)
+#let end = box[#metadata(none)<end>]
+#block(width: 100%, end)
+#block(width: 100%, align: right, end)
+#block(width: 100%, align: center + bottom, end)
+#end
+
+#locate(loc => {
+  let positions = query(<end>, loc).map(it => it.location().position())
+  let xs = positions.map(it => calc.round(it.x / 1pt, digits: 2))
+  test(xs, (0, 100, 50, 0))
+  let ys = positions.map(it => it.y)
+  test(ys.at(1) - ys.at(0), ys.at(2) - ys.at(1))
+})
@@ -3,3 +3,12 @@
 ---
 AB #h(1fr) CD \
 #hide[A]B #h(1fr) C#hide[D]
+
+---
+// Ref: false
+// Hidden content can give up its space.
+#style(styles => {
+  let width(body) = measure(body, styles).width
+  test(width[A#hide[Hello]B] > width[AB], true)
+  test(width[A#hide(reserve: false)[Hello]B], width[AB])
+})
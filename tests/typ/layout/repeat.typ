@@ -38,6 +38,146 @@ A#box(width: 1fr, repeat(rect(width: 6em, height: 0.7em)))B
 #set text(dir: rtl)
 ريجين#box(width: 1fr, repeat(rect(width: 4em, height: 0.7em)))سون
 
+---
+// Ref: false
+// Snapped repeats start at a multiple of their body's width.
+#set page(width: 120pt)
+#let dot = box(width: 5pt, height: 1pt)[#metadata(none)<dot>]
+A #box(width: 1fr, repeat(snap: true, dot)) 1 \
+Longer #box(width: 1fr, repeat(snap: true, dot)) 2
+
+#locate(loc => {
+  let xs = query(<dot>, loc).map(it => it.location().position().x / 5pt)
+  test(xs.len(), 2)
+  test(xs.all(x => calc.abs(x - calc.round(x)) < 0.001), true)
+})
+
+---
+// Ref: false
+// Justified repeats start at the edge of their box, others are aligned.
+#let dot = box(width: 10pt, height: 1pt)[#metadata(none)<dot>]
+#let start = box[#metadata(none)<start>]
+
+#start#box(width: 35pt, repeat(dot))
+
+#start#box(width: 35pt, repeat(justify: false, dot))
+
+#align(right)[#start#box(width: 35pt, repeat(justify: false, dot))]
+
+#align(center)[#start#box(width: 35pt, repeat(justify: false, dot))]
+
+#locate(loc => {
+  let xs(label) = query(label, loc).map(it => it.location().position().x)
+  let offsets = xs(<dot>).zip(xs(<start>)).map(((a, b)) => {
+    calc.round((a - b) / 1pt, digits: 2)
+  })
+  test(offsets, (0, 0, 5, 2.5))
+})
+
+---
+// Ref: false
+// Only as many instances as fit with the gap between them are shown. Aligned
+// to the right, the first instance reveals how many there are.
+#let dot = box(width: 10pt, height: 1pt)[#metadata(none)<dot>]
+#let start = box[#metadata(none)<start>]
+#set align(right)
+
+#start#box(width: 25pt, repeat(justify: false, dot))
+
+#start#box(width: 25pt, repeat(justify: false, gap: 10pt, dot))
+
+#start#box(width: 35pt, repeat(justify: false, gap: 5pt, dot))
+
+#locate(loc => {
+  let xs(label) = query(label, loc).map(it => it.location().position().x)
+  let offsets = xs(<dot>).zip(xs(<start>)).map(((a, b)) => {
+    calc.round((a - b) / 1pt, digits: 2)
+  })
+  test(offsets, (5, 15, 10))
+})
+
+---
+// Ref: false
+// The trailing gap is kept free after the last instance.
+#let dot = box(width: 10pt, height: 1pt)[#metadata(none)<dot>]
+#let start = box[#metadata(none)<start>]
+#set align(right)
+
+#start#box(width: 35pt, repeat(justify: false, dot))
+
+#start#box(width: 35pt, repeat(justify: false, trailing: 4pt, dot))
+
+#locate(loc => {
+  let xs(label) = query(label, loc).map(it => it.location().position().x)
+  let offsets = xs(<dot>).zip(xs(<start>)).map(((a, b)) => {
+    calc.round((a - b) / 1pt, digits: 2)
+  })
+  test(offsets, (5, 1))
+})
+
+---
+// Ref: false
+// In an outline, the trailing gap is the only space before the page number,
+// no matter how wide it is.
+#set page(footer: none, numbering: (n, ..) => [#box[#metadata(none)<num>]#n])
+#set align(right)
+#let dot = box(width: 5pt, height: 1pt)[#metadata(none)<dot>]
+#outline(fill: repeat(justify: false, trailing: 4pt, dot))
+
+= A
+#pagebreak()
+#counter(page).update(100)
+= B
+
+#locate(loc => {
+  let xs(label) = query(label, loc).map(it => it.location().position().x)
+  let rests = xs(<num>).zip(xs(<dot>)).map(((num, dot)) => {
+    calc.rem(calc.round((num - dot - 4pt) / 1pt, digits: 2), 5)
+  })
+  test(rests, (0, 0))
+})
+
+---
+// Ref: false
+// Without complete instances, the repeat runs from edge to edge and the
+// instance that doesn't fit is clipped at the end of the line.
+#let dot(key) = box(width: 10pt, height: 1pt)[#metadata(key)<dot>]
+#let start(key) = box[#metadata(key)<start>]
+
+#start("a")#box(width: 35pt, repeat(justify: false, dot("a")))
+
+#start("b")#box(width: 35pt, repeat(complete: false, dot("b")))
+
+#align(right)[#start("c")#box(width: 35pt, repeat(complete: false, dot("c")))]
+
+#locate(loc => {
+  let xs(label, key) = query(label, loc)
+    .filter(it => it.value == key)
+    .map(it => it.location().position().x)
+  let offsets(key) = xs(<dot>, key).map(x => {
+    calc.round((x - xs(<start>, key).first()) / 1pt, digits: 2)
+  })
+  test(offsets("a"), (0, 10, 20))
+  test(offsets("b"), (0, 10, 20, 30))
+  test(offsets("c"), (-5, 5, 15, 25))
+})
+
+---
+// Ref: false
+// In an outline, a fill of complete dots stops earlier than one that runs
+// from edge to edge.
+#set page(width: 100pt)
+#let dot(key) = box(width: 7pt, height: 1pt)[#metadata(key)<dot>]
+#outline(title: none, fill: repeat(justify: false, dot("complete")))
+#outline(title: none, fill: repeat(complete: false, dot("edge")))
+
+= A
+
+#locate(loc => {
+  let count(key) = query(<dot>, loc).filter(it => it.value == key).len()
+  test(count("edge"), count("complete") + 1)
+})
+
 ---
 // Error: 2:2-2:13 repeat with no size restrictions
 #set page(width: auto)
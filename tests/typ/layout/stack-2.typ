@@ -21,3 +21,26 @@ World! 🌍
           #v(1fr)
   #h(1fr) Hi you!
 ]
+
+---
+// Ref: false
+// Ratio and keyword alignments are ordered by their position along the
+// stack's main axis.
+#set page(width: 100pt, height: auto, margin: 0pt)
+#let item(key) = box(width: 10pt, height: 5pt)[#metadata(key)<item>]
+#stack(dir: ltr, align(end, item("a")), align(0%, item("b")))
+#stack(dir: ltr, align(25%, item("c")), align(center, item("d")))
+#stack(dir: rtl, align(right, item("e")), align(25%, item("f")))
+#stack(dir: rtl, align(50%, item("g")), align(end, item("h")))
+
+#locate(loc => {
+  let xs = query(<item>, loc).map(it => {
+    (it.value, calc.round(it.location().position().x / 1pt, digits: 2))
+  })
+  test(xs, (
+    ("a", 80), ("b", 90),
+    ("c", 20), ("d", 50),
+    ("e", 90), ("f", 20),
+    ("g", 50), ("h", 40),
+  ))
+})
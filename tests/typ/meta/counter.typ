@@ -50,3 +50,100 @@ At Beta, it was #locate(loc => {
 #figure(caption: [Four 'C's], kind: image, supplement: "Figure")[_CCCC!_]
 #counter(figure.where(kind: image)).update(n => n + 3)
 #figure(caption: [Four 'D's], kind: image, supplement: "Figure")[_DDDD!_]
+
+---
+// Ref: false
+// A numbering function with a `counter` parameter learns which counter it
+// displays.
+#let kinds = state("kinds", ())
+#let images = counter(figure.where(kind: image))
+#let number(..nums, counter: none) = {
+  kinds.update(k => k + (if counter == images { "image" } else { "other" },))
+  numbering("1", ..nums)
+}
+
+#set heading(numbering: number)
+#set figure(numbering: number)
+
+= Alpha
+#figure(caption: [Tiger], kind: image, supplement: "Figure")[_Roar!_]
+#numbering(number, 1)
+
+#locate(loc => test(kinds.final(loc), ("other", "image", "other")))
+
+---
+// Ref: false
+// Stepping a level resets the deeper levels.
+#let nested = counter("nested")
+#nested.update((3, 4, 1))
+#nested.step(level: 2)
+#locate(loc => test(nested.at(loc), (3, 5)))
+#nested.step(level: 3)
+#locate(loc => test(nested.at(loc), (3, 5, 1)))
+
+---
+// Ref: false
+// Counters can be retrieved at an element matched by a selector.
+#set heading(numbering: "1.")
+= A
+= B <b>
+== C
+
+#locate(loc => {
+  test(counter(heading).at(<b>), (2,))
+  test(counter(heading).at(heading.where(level: 2)), (2, 1))
+  test(counter(heading).at(loc), (2, 1))
+})
+
+---
+// Error: 16-46 selector does not match any element
+#locate(loc => counter(heading).at(<missing>))
+
+---
+// Error: 16-44 selector matches multiple elements
+#locate(loc => counter(heading).at(heading))
+= A
+= B
+
+---
+// Ref: false
+// A counter for a selector counts the matching elements in document order.
+#let figures = counter(selector(figure))
+#let marked = counter(selector(heading).or(<mark>))
+#set heading(numbering: "1.")
+
+#figure(rect(), caption: [A])
+= B
+#figure(rect(), caption: [C]) <mark>
+#figure(rect(), caption: [D])
+
+#locate(loc => {
+  let counts = query(figure, loc).map(it => figures.at(it.location()))
+  test(counts, ((1,), (2,), (3,)))
+  test(figures.final(loc), (3,))
+  test(marked.final(loc), (2,))
+})
+
+---
+// Ref: false
+// An automatic numbering is picked from the context of the counter.
+#set page(numbering: "i")
+#set heading(numbering: "(I.a)")
+#let span(body) = [#box[#metadata(none)<start>]#body#box[#metadata(none)<end>]]
+#counter(page).update(3)
+
+= A
+== B
+
+#span(counter(page).display())
+#span(counter(page).display(auto))
+#span(counter(heading).display())
+#span(counter("custom").display())
+
+#locate(loc => style(styles => {
+  let xs(label) = query(label, loc).map(it => it.location().position().x)
+  let widths = xs(<end>).zip(xs(<start>)).map(((end, start)) => end - start)
+  let expected = ([iii], [iii], [(I.a)], [0]).map(it => measure(it, styles).width)
+  let close(a, b) = calc.abs((a - b) / 1pt) < 0.01
+  test(widths.zip(expected).all(((a, b)) => close(a, b)), true)
+}))
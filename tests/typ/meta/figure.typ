@@ -109,3 +109,28 @@ We can clearly see that @fig-cylinder and
   table(columns: 2)[a][b],
   caption: [The table with custom separator.],
 )
+
+---
+// Ref: false
+// Chapter-relative numbers restart with each chapter and show up the same
+// way in the captions and in the list of figures.
+#let seen = state("seen", ())
+#set heading(numbering: "1.")
+#set figure(chapter-level: 1, numbering: (..nums) => {
+  seen.update(l => l + (nums.pos(),))
+  numbering("1.1", ..nums)
+})
+#outline(target: figure)
+
+= One
+#figure(rect(), caption: [A])
+
+= Two
+#figure(rect(), caption: [B])
+== Nested
+#figure(rect(), caption: [C])
+
+#locate(loc => {
+  let numbers = ((1, 1), (2, 1), (2, 2))
+  test(seen.final(loc), numbers + numbers)
+})
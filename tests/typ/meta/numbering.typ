@@ -8,6 +8,19 @@
   [ for #i \ ]
 }
 
+---
+// Ref: false
+#test(numbering("1.a.i", 3, 2, 4), "3.b.iv")
+#test(numbering("i", 3999), "mmmcmxcix")
+#test(numbering("i", 4001), "i\u{305}v\u{305}i")
+#test(numbering("I", 4001), "I\u{305}V\u{305}I")
+
+---
+// Ref: false
+// Numbers beyond the pattern's pieces repeat the last one.
+#test(numbering("1.a", 1, 2, 3), "1.b.c")
+#test(numbering("(1.a)", 1, 2, 3), "(1.b.c)")
+
 ---
 #for i in range(0, 4) {
   numbering("A", i)
@@ -82,6 +95,30 @@
   [ for #i \ ]
 }
 
+---
+// Ref: false
+// Zeros in front of an arabic counting symbol pad the number.
+#test(numbering("001", 1), "001")
+#test(numbering("001", 10), "010")
+#test(numbering("001", 100), "100")
+#test(numbering("001", 1000), "1000")
+#test(numbering("001.01", 7, 3, 12), "007.03.12")
+#test(numbering("0i.0a", 4, 2), "0iv.0b")
+#test(numbering("[001]", 5), "[005]")
+
 ---
 // Error: 17-19 number must be at least zero
 #numbering("1", -1)
+
+---
+// Ref: false
+// Each number is displayed by the numbering for its level.
+#let words(n) = ("One", "Two", "Three").at(n - 1)
+#test(numbering((words, ".1"), 1), "One")
+#test(numbering((words, ".1"), 1, 1), "One.1")
+#test(numbering((words, ".1"), 2, 1, 3), "Two.1.3")
+#test(numbering(("I", "-a"), 3, 2), "III-b")
+
+---
+// Error: 12-14 array must contain at least one numbering
+#numbering((), 1)
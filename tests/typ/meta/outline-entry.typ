@@ -58,3 +58,295 @@
 #outline(target: cite)
 #cite("arrgh", "distress",  supplement: [p. 22])
 #bibliography("/files/works.bib")
+
+---
+// Error: 25-31 expected `start` or `end`
+#outline(page-position: center)
+
+---
+// Ref: false
+#let pages = state("pages", ())
+#show outline.entry: it => pages.update(p => p + (it.page.text,))
+#outline(columns: 2)
+
+= A
+#pagebreak()
+= B
+
+#locate(loc => test(pages.final(loc), ("1", "2")))
+
+---
+// Ref: false
+#set page(width: 120pt)
+#outline(ellipsis: […])
+
+= Short
+= A heading that is far too long for a single line
+
+---
+// Ref: false
+// A show-set rule on the outline takes precedence over the outline's own
+// styles, so it can number the outline's title.
+#show outline: set heading(numbering: "1.")
+#outline()
+
+#locate(loc => test(query(heading, loc).first().numbering, "1."))
+
+---
+// Ref: false
+#show outline.entry: it => [#metadata(none)<entry>#it]
+#outline(title-gap: 1em)
+#pagebreak()
+#outline(title-gap: 3em)
+
+= A
+
+#locate(loc => {
+  let ys = query(<entry>, loc).map(it => it.location().position().y)
+  test(calc.abs((ys.at(1) - ys.at(0)) / 1pt - 22) < 0.01, true)
+})
+
+---
+// Ref: false
+// Headings can have a prefix in front of their number.
+#let bodies = state("bodies", ())
+#show outline.entry: it => bodies.update(b => b + (it.body,))
+#set heading(numbering: "1")
+#show heading.where(level: 1): set heading(outline-prefix: [Chapter])
+#outline()
+
+= Introduction
+== Scope
+
+#locate(loc => test(bodies.final(loc), ([Chapter 1 Introduction], [1.1 Scope])))
+
+---
+// Ref: false
+// Entries can display the range of pages their section spans.
+#let pages = state("pages", ())
+#show outline.entry: it => pages.update(p => p + (it.page,))
+#outline(page-range: true)
+
+= A
+#pagebreak()
+== B
+#pagebreak()
+= C
+= D
+
+#let range(start, end) = [#start#"–"#end]
+#locate(loc => test(pages.final(loc), (
+  range("1", "3"), range("2", "3"), [#"3"], [#"3"],
+)))
+
+---
+// Ref: false
+// Entries can link to the page of their element instead.
+#outline(link-target: "page")
+
+= A
+#pagebreak()
+= B
+
+---
+// Error: 23-30 expected "element" or "page"
+#outline(link-target: "title")
+
+---
+// Ref: false
+// An automatic prefix is the heading's supplement, just like in references.
+#let bodies = state("bodies", ())
+#show outline.entry: it => bodies.update(b => b + (it.body,))
+#set heading(numbering: "1", outline-prefix: auto)
+#outline()
+
+= Introduction <intro>
+#heading(supplement: [Part], level: 2)[Scope]
+#heading(numbering: none)[Appendix]
+
+#locate(loc => test(bodies.final(loc), (
+  [Section 1 Introduction], [Part 1.1 Scope], [Appendix],
+)))
+
+---
+// Ref: false
+// Figures are outlined with their outline caption if they have one.
+#let bodies = state("bodies", ())
+#show outline.entry: it => bodies.update(b => b + (it.body.children.last(),))
+#outline(target: figure)
+
+#figure(rect(), caption: [Long], outline-caption: [Short])
+#figure(rect(), caption: [Long])
+#figure(rect(), outline-caption: [Short])
+
+#locate(loc => test(bodies.final(loc), ([Short], [Long])))
+
+---
+// Ref: false
+// Numbered equations are listed with their number and label.
+#let bodies = state("bodies", ())
+#show outline.entry: it => bodies.update(b => b + (it.body.children.last(),))
+#set math.equation(numbering: "(1)")
+#outline(target: math.equation)
+
+$ a = b $ <einstein>
+$ c = d $
+#[
+  #set math.equation(numbering: none)
+  $ e = f $
+]
+
+#locate(loc => test(bodies.final(loc), ([#": einstein"], [(2)])))
+
+---
+// Ref: false
+// Entries can display the total number of pages.
+#let pages = state("pages", ())
+#show outline.entry: it => pages.update(p => p + (it.page.text,))
+#set page(numbering: "1 / 1")
+#outline(page-total: true)
+
+= A
+#pagebreak()
+#pagebreak()
+= B
+
+#locate(loc => test(pages.final(loc), ("1 / 3", "3 / 3")))
+
+---
+// Ref: false
+// Quotes can be outlined along with their attribution.
+#let bodies = state("bodies", ())
+#show outline.entry: it => bodies.update(b => b + (it.body,))
+#outline(target: quote)
+
+#quote(attribution: [René Descartes])[cogito, ergo sum]
+#quote(block: true)[I am a Berliner.]
+
+#locate(loc => test(bodies.final(loc), (
+  [cogito, ergo sum#" — "René Descartes],
+  [I am a Berliner.],
+)))
+
+---
+// Ref: false
+// Numbered headings and figures assemble their entries the same way.
+#let bodies = state("bodies", ())
+#set heading(numbering: "1")
+#set figure.caption(separator: [ ])
+#show outline.entry: it => bodies.update(b => b + (it.body,))
+#outline(target: selector(heading).or(figure))
+
+= A
+#figure(rect(), caption: [A], supplement: [])
+
+#locate(loc => {
+  let (heading, figure) = bodies.final(loc)
+  test(heading, figure)
+})
+
+---
+// Ref: false
+// The number gap replaces the separator between number and title.
+#let rests = state("rests", ())
+#set heading(numbering: "1.")
+#show outline.entry: it => rests.update(r => {
+  r + (if it.body.has("children") { it.body.children.slice(-2) } else { it.body },)
+})
+#outline(target: selector(heading).or(figure), number-gap: 10pt)
+#outline(target: figure, numbering: "I", number-gap: 10pt)
+
+= A
+#figure(rect(), caption: [B])
+#figure(rect(), caption: [C], numbering: none)
+
+#locate(loc => test(rests.final(loc), (
+  (h(10pt), [A]),
+  (h(10pt), [B]),
+  [C],
+  (h(10pt), [B]),
+  (h(10pt), [C]),
+)))
+
+---
+// Ref: false
+// A custom entry receives the level, body and page of each entry.
+#let entries = state("entries", ())
+#outline(entry: (level, body, page) => {
+  entries.update(e => e + ((level, body, page),))
+  [#body #page]
+})
+#outline(page-numbers: none, entry: (level, body, page) => {
+  entries.update(e => e + ((level, body, page),))
+  body
+})
+
+= A
+== B
+
+#locate(loc => test(entries.final(loc), (
+  (1, [A], [1]), (2, [B], [1]),
+  (1, [A], none), (2, [B], none),
+)))
+
+---
+// Ref: false
+// The bodies are styled by level, with the last style used for deeper levels.
+#let bodies = state("bodies", ())
+#show outline.entry: it => bodies.update(b => b + (it.body,))
+#outline(level-style: (strong, none, emph))
+
+= A
+== B
+=== C
+==== D
+= E
+
+#locate(loc => test(bodies.final(loc), (
+  strong[A], [B], emph[C], emph[D], strong[E],
+)))
+
+---
+// Ref: false
+// Annotations line up in a fixed-width column at the end of each entry.
+#set page(width: 200pt, height: auto, margin: 0pt)
+#set text(size: 10pt)
+#outline(annotation: it => [#box[#metadata(none)<note>]v#it.level])
+#outline(annotation: it => [#box[#metadata(none)<note>]], page-numbers: none)
+#outline(annotation: it => [#box[#metadata(none)<note>]], annotation-width: 5em)
+
+= Introduction
+== Scope of the work
+= B
+
+#locate(loc => {
+  let xs = query(<note>, loc).map(it => calc.round(it.location().position().x / 1pt, digits: 2))
+  test(xs, (170, 170, 170, 170, 170, 170, 150, 150, 150))
+})
+
+---
+// Ref: false
+// The gap replaces the spacing between entries. A ratio is relative to the
+// page and a fraction spreads the entries over the page.
+#set page(height: 200pt, margin: 0pt)
+#show outline.entry: it => [#metadata(none)<entry>#it]
+#for gap in (0pt, 0.5em, 10%, 1fr) {
+  outline(title: none, gap: gap)
+  pagebreak()
+}
+
+= A
+= B
+= C
+
+#locate(loc => {
+  let ys = query(<entry>, loc).map(it => it.location().position().y / 1pt)
+  let (zero, em, ratio, fr) = range(4).map(i => {
+    let (a, b, c) = ys.slice(3 * i, 3 * i + 3)
+    test(calc.abs(c - b - (b - a)) < 0.01, true)
+    b - a
+  })
+  test(calc.abs(em - zero - 5.5) < 0.01, true)
+  test(calc.abs(ratio - zero - 20) < 0.01, true)
+  test(fr > ratio, true)
+})
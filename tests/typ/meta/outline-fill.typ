@@ -0,0 +1,72 @@
+// Test the outline's fill.
+// Ref: false
+
+---
+#let fills = state("fills", ())
+#show outline.entry: it => fills.update(f => f + (it.fill != none,))
+#outline(fill: n => if n > 0 { repeat[.] })
+
+= A
+== B
+=== C
+= D
+
+#locate(loc => test(fills.final(loc), (false, true, true, false)))
+
+---
+// Error: 2-23 expected content or none, found integer
+#outline(fill: n => 1)
+
+= Heading
+
+---
+// An array of fills is indexed by level and repeats its last item.
+#let fills = state("fills", ())
+#show outline.entry: it => fills.update(f => f + (it.fill,))
+#outline(fill: ([-], none, [.]))
+
+= A
+== B
+=== C
+==== D
+
+#locate(loc => test(fills.final(loc), ([-], none, [.], [.])))
+
+---
+// Error: 16-18 array must contain at least one fill
+#outline(fill: ())
+
+---
+// Error: 16-20 expected content or none, found integer
+#outline(fill: (1,))
+
+---
+// A length fill puts a fixed gap between the title and the page number.
+#set page(footer: none, numbering: (n, ..) => [#box[#metadata(none)<num>]#n])
+#set text(size: 10pt)
+#show outline.entry: it => [#box[#metadata(none)<start>]#it]
+#outline(fill: 1em)
+
+= Introduction
+= B
+
+#locate(loc => style(styles => {
+  let xs(label) = query(label, loc).map(it => it.location().position().x)
+  let gaps = xs(<num>).zip(xs(<start>), ([Introduction], [B])).map(((num, start, body)) => {
+    calc.round((num - start - measure(body, styles).width) / 1pt, digits: 2)
+  })
+  test(gaps, (10, 10))
+}))
+
+---
+// Entries are only filled from the given nesting level on.
+#let fills = state("fills", ())
+#show outline.entry: it => fills.update(f => f + (it.fill != none,))
+#outline(fill-from-level: 2)
+
+= A
+== B
+=== C
+= D
+
+#locate(loc => test(fills.final(loc), (false, true, true, false)))
@@ -0,0 +1,372 @@
+// Test which elements are included in the outline.
+// Ref: false
+
+---
+#let levels = state("levels", ())
+#show outline.entry: it => levels.update(l => l + (it.level,))
+#outline(start: 2)
+
+= A
+== B
+=== C
+= D
+== E
+
+#locate(loc => test(levels.final(loc), (2, 3, 2)))
+
+---
+// Error: 2-29 outline start must not be greater than its depth
+#outline(start: 3, depth: 2)
+
+---
+#let pages = state("pages", ())
+#show outline.entry: it => pages.update(p => p + (it.page != [],))
+#outline(page-numbers: it => it.level > 1)
+
+= A
+== B
+= C
+
+#locate(loc => test(pages.final(loc), (false, true, false)))
+
+---
+#let kinds = state("kinds", ())
+#show outline.entry: it => kinds.update(k => k + (it.element.func(),))
+#outline(target: selector(heading).or(figure))
+
+= A
+#figure(rect[B], caption: [B])
+== C
+
+#locate(loc => test(kinds.final(loc), (heading, figure, heading)))
+
+---
+#let levels = state("levels", ())
+#show outline.entry: it => levels.update(l => l + (it.level,))
+#outline(filter: it => it.at("label", default: none) != <skip>)
+
+= A
+= B <skip>
+== C
+= D
+
+#locate(loc => test(levels.final(loc), (1, 2, 1)))
+
+---
+// Error: 2-26 expected boolean, found integer
+#outline(filter: it => 1)
+
+= A
+
+---
+#let levels = state("levels", ())
+#show outline.entry: it => levels.update(l => l + (it.level,))
+#outline(depth: (2, 3))
+
+= A
+== B
+=== C
+==== D
+== E
+
+#locate(loc => test(levels.final(loc), (2, 3, 2)))
+
+---
+#let levels = state("levels", ())
+#show outline.entry: it => levels.update(l => l + (it.level,))
+#outline(depth: (3, none))
+
+= A
+=== B
+==== C
+
+#locate(loc => test(levels.final(loc), (3, 4)))
+
+---
+// The maximum depth is inclusive for every kind of target. Figures are on the
+// first level, so they don't let deeper headings in after them.
+#let entries = state("entries", ())
+#show outline.entry: it => entries.update(e => e + ((it.element.func(), it.level),))
+#outline(target: selector(heading).or(figure), depth: 2)
+
+= A
+== B
+#figure(rect(), caption: [C])
+=== D
+== E
+=== F
+
+#locate(loc => test(entries.final(loc), (
+  (heading, 1), (heading, 2), (figure, 1), (heading, 2),
+)))
+
+---
+// Without a maximum depth, there is no upper bound.
+#let levels = state("levels", ())
+#show outline.entry: it => levels.update(l => l + (it.level,))
+#outline(depth: none)
+#heading(level: 1)[A]
+#heading(level: 12)[B]
+
+#locate(loc => test(levels.final(loc), (1, 12)))
+
+---
+// A minimum equal to the maximum includes just that level.
+#let levels = state("levels", ())
+#show outline.entry: it => levels.update(l => l + (it.level,))
+#outline(depth: (2, 2))
+
+= A
+== B
+=== C
+
+#locate(loc => test(levels.final(loc), (2,)))
+
+---
+// Error: 17-26 depth array must contain exactly two entries
+#outline(depth: (1, 2, 3))
+
+---
+// A heading matched by several parts of the selector is listed once, as
+// the introspector never returns an element twice for one query.
+#let entries = state("entries", ())
+#show outline.entry: it => entries.update(l => l + (it.element.body,))
+#outline(target: heading.or(selector(heading).after(<a>)).or(<a>))
+
+= A <a>
+= B
+
+#locate(loc => test(entries.final(loc), ([A], [B])))
+
+---
+// Figures don't become the parent of the headings after them.
+#set text(size: 10pt)
+#show outline.entry: it => [#metadata(none)<entry>#it]
+#outline(target: selector(heading).or(figure), indent: 1em)
+
+= A
+#figure(rect[B], caption: [B])
+== C
+
+#locate(loc => {
+  let xs = query(<entry>, loc).map(it => it.location().position().x)
+  let offsets = xs.map(x => calc.round((x - xs.first()) / 1pt, digits: 2))
+  test(offsets, (0, 0, 10))
+})
+
+---
+// The filter can depend on the layout.
+#let pages = state("pages", ())
+#show outline.entry: it => pages.update(p => p + (it.element.location().page(),))
+#outline(filter: it => calc.even(it.location().page()))
+
+= A
+#pagebreak()
+= B
+= C
+#pagebreak()
+= D
+
+#locate(loc => test(pages.final(loc), (2, 2)))
+
+---
+// An empty outline can be hidden along with its title.
+#outline(hide-empty: true)
+#outline(target: figure, hide-empty: true)
+
+#locate(loc => test(query(heading, loc), ()))
+
+---
+// An outline with entries is still shown.
+#outline(hide-empty: true)
+
+= A
+
+#locate(loc => test(query(heading, loc).len(), 2))
+
+---
+// A title displayed as content is not a heading.
+#outline(title-as: "content")
+#outline(title: [Contents], title-as: "content")
+
+= A
+
+#locate(loc => test(query(heading, loc).len(), 1))
+
+---
+// A second outline doesn't list the title of the first one.
+#let bodies = state("bodies", ())
+#outline(title: [First], title-bookmarked: true)
+#[
+  #show outline.entry: it => bodies.update(b => b + (it.body,))
+  #outline(title: [Second])
+]
+
+= A
+
+#locate(loc => test(bodies.final(loc), ([A],)))
+#locate(loc => test(query(heading, loc).map(it => it.bookmarked), (true, false, auto)))
+
+---
+// Whether a heading is bookmarked doesn't affect whether it is outlined.
+#let bodies = state("bodies", ())
+#show outline.entry: it => bodies.update(b => b + (it.body,))
+#outline()
+
+#heading(bookmarked: false)[A]
+#heading(outlined: false, bookmarked: true)[B]
+#heading(outlined: false)[C]
+
+#locate(loc => test(bodies.final(loc), ([A],)))
+#locate(loc => {
+  let headings = query(heading, loc).slice(1)
+  test(headings.map(it => (it.outlined, it.bookmarked)), (
+    (true, false), (false, true), (false, auto),
+  ))
+})
+
+---
+// Grouped entries are listed below a subheading for their kind.
+#let bodies = state("bodies", ())
+#show outline.entry: it => bodies.update(b => b + (it.body.children.last(),))
+#outline(target: figure, grouped: true)
+
+#figure(rect(), caption: [A])
+#figure(table[], caption: [B])
+#figure(rect(), caption: [C])
+
+#locate(loc => test(bodies.final(loc), ([A], [C], [B])))
+#locate(loc => test(
+  query(heading.where(level: 2), loc).map(it => it.body),
+  ([Figures], [Tables]),
+))
+
+---
+// Consecutively grouped entries get a localized subheading wherever the kind
+// changes.
+#let bodies = state("bodies", ())
+#show outline.entry: it => bodies.update(b => b + (it.body.children.last(),))
+#outline(target: figure, grouped: "consecutive")
+#text(lang: "de", outline(target: figure, grouped: "consecutive"))
+
+#figure(rect(), caption: [A])
+#figure(rect(), caption: [B])
+#figure(table[], caption: [C])
+#figure(rect(), caption: [D])
+
+#locate(loc => test(bodies.final(loc).slice(0, 4), ([A], [B], [C], [D])))
+#locate(loc => test(
+  query(heading.where(level: 2), loc).map(it => it.body),
+  ([Figures], [Tables], [Figures], [Abbildungen], [Tabellen], [Abbildungen]),
+))
+
+---
+// A local outline only lists the elements of its section.
+#let bodies = state("bodies", ())
+#show outline.entry: it => bodies.update(b => b + (it.body,))
+
+= A
+#outline(local: true)
+== B
+=== C
+== D
+
+= E
+== F
+
+#locate(loc => test(bodies.final(loc), ([B], [C], [D])))
+
+---
+// A local outline before the first heading lists all elements.
+#let bodies = state("bodies", ())
+#show outline.entry: it => bodies.update(b => b + (it.body,))
+#outline(local: true)
+
+= A
+== B
+
+#locate(loc => test(bodies.final(loc), ([A], [B])))
+
+---
+// Sorted entries are ordered by their key.
+#let bodies = state("bodies", ())
+#show outline.entry: it => bodies.update(b => b + (it.body.children.last(),))
+#outline(target: figure, sort: it => it.caption.body.text)
+
+#figure(rect(), caption: [C])
+#figure(rect(), caption: [A])
+#figure(rect(), caption: [B])
+
+#locate(loc => test(bodies.final(loc), ([A], [B], [C])))
+
+---
+// Sorted headings are not nested in each other.
+#show outline.entry: it => [#metadata(none)<entry>#it]
+#outline(sort: it => it.body.text, indent: 1em)
+
+= B
+== A
+
+#locate(loc => {
+  let xs = query(<entry>, loc).map(it => it.location().position().x)
+  test(xs.at(0), xs.at(1))
+})
+
+---
+// Error: 2-58 cannot compare string and integer
+#outline(sort: it => if it.level == 1 { "a" } else { 1 })
+
+= A
+== B
+
+---
+// Entries past the maximum page are left out, but those on it are kept.
+#let bodies = state("bodies", ())
+#show outline.entry: it => bodies.update(b => b + (it.body,))
+#outline(max-page: 2)
+
+= A
+#pagebreak()
+= B
+== C
+#pagebreak()
+= D
+
+#locate(loc => test(bodies.final(loc), ([A], [B], [C])))
+
+---
+// The titles of the outlines aren't listed, even when targeting all headings.
+#let bodies = state("bodies", ())
+#show outline.entry: it => bodies.update(b => b + (it.body,))
+#outline(target: heading)
+#outline(target: heading, title-as: "content")
+
+= A
+
+#locate(loc => test(bodies.final(loc), ([A], [A])))
+
+---
+// Warning: 2-48 outlines cannot be outlined
+// Hint: 2-48 they are left out of the outline
+#outline(target: selector(heading).or(outline))
+
+= A
+
+---
+// Ref: false
+// A local outline finds its section from its own location, also when the
+// section is the last one, and passes its page on to a title function.
+#let bodies = state("bodies", ())
+#show outline.entry: it => bodies.update(b => b + (it.element.body,))
+= A
+== B
+= C
+#pagebreak()
+#outline(local: true, title: it => [Page #it.page])
+== D
+=== E
+
+#locate(loc => {
+  test(bodies.final(loc), ([D], [E]))
+  test(query(heading.where(outlined: false), loc).map(it => it.body), ([Page 2],))
+})
@@ -5,7 +5,11 @@
 #set page(width: 200pt)
 #set heading(numbering: "1.a.")
 #outline()
+// Warning: 18-23 boolean values for `indent` are deprecated
+// Hint: 18-23 use `none` instead
 #outline(indent: false)
+// Warning: 18-22 boolean values for `indent` are deprecated
+// Hint: 18-22 use `auto` instead
 #outline(indent: true)
 #outline(indent: none)
 #outline(indent: auto)
@@ -31,7 +35,11 @@
 // Without heading numbering
 #set page(width: 200pt)
 #outline()
+// Warning: 18-23 boolean values for `indent` are deprecated
+// Hint: 18-23 use `none` instead
 #outline(indent: false)
+// Warning: 18-22 boolean values for `indent` are deprecated
+// Hint: 18-22 use `auto` instead
 #outline(indent: true)
 #outline(indent: none)
 #outline(indent: auto)
@@ -58,3 +66,131 @@
 #outline(indent: n => (a: "dict"))
 
 = Heading
+
+---
+// Ref: false
+#let levels = state("levels", ())
+#outline(indent: (n, it) => {
+  levels.update(l => l + (it.level,))
+  h(n * 1em)
+})
+
+= A
+== B
+
+#locate(loc => test(levels.final(loc), (1, 2)))
+
+---
+// Ref: false
+// The auto indent of a child entry matches the width of its ancestors'
+// numbers, each followed by one space.
+#set heading(numbering: "1.a.i.")
+#show outline.entry: it => [#metadata(none)<entry>#it]
+#outline(indent: auto)
+
+= A
+== B
+=== C
+
+#locate(loc => style(styles => {
+  let width(body) = measure(body, styles).width
+  let space = width[1 x] - width[1x]
+  let xs = query(<entry>, loc).map(it => it.location().position().x)
+  let close(a, b) = calc.abs((a - b) / 1pt) < 0.01
+  test(close(xs.at(1) - xs.at(0), width[1.] + space), true)
+  test(close(xs.at(2) - xs.at(0), width[1.] + space + width[1.a.] + space), true)
+}))
+
+---
+// Ref: false
+// A length indent is clamped to the maximum indent.
+#set text(size: 10pt)
+#show outline.entry: it => [#metadata(none)<entry>#it]
+#outline(indent: 2em, max-indent: 3em)
+
+= A
+== B
+=== C
+==== D
+
+#locate(loc => {
+  let xs = query(<entry>, loc).map(it => it.location().position().x)
+  let offsets = xs.map(x => calc.round((x - xs.first()) / 1pt, digits: 2))
+  test(offsets, (0, 20, 30, 30))
+})
+
+---
+// Ref: false
+// The document's first-line indent doesn't apply to the entries.
+#set par(first-line-indent: 2em)
+#show outline.entry: it => [#box[#metadata(none)<entry>]#it]
+
+#lorem(5)
+
+#outline(title: none)
+#outline(title: none, hanging-indent: true)
+#block[#box[#metadata(none)<flush>]]
+
+= A
+= B
+
+#locate(loc => {
+  let xs(label) = query(label, loc).map(it => it.location().position().x)
+  let flush = xs(<flush>).first()
+  test(xs(<entry>).all(x => x == flush), true)
+  test(xs(<entry>).len(), 4)
+})
+
+---
+// Error: 18-21 outline indent cannot be fractional
+// Hint: 18-21 use a length like `2em` instead
+#outline(indent: 1fr)
+
+---
+// Ref: false
+// A root entry shifts all other entries down by one level.
+#set text(size: 10pt)
+#show outline.entry: it => [#box[#metadata(none)<entry>]#it]
+#outline(indent: 1em)
+#outline(indent: 1em, root: auto)
+#outline(indent: 1em, root: <intro>)
+
+#heading(outlined: false)[Hidden]
+= Book
+= Introduction <intro>
+== Scope
+= Conclusion
+
+#locate(loc => {
+  let xs = query(<entry>, loc).map(it => it.location().position().x)
+  let offsets = xs.map(x => calc.round((x - xs.first()) / 1pt, digits: 2))
+  test(offsets, (
+    0, 0, 10, 0,
+    0, 10, 20, 10,
+    10, 0, 10, 10,
+  ))
+})
+
+---
+// Ref: false
+// A root that is left out of the outline doesn't indent the other entries.
+#set text(size: 10pt)
+#show outline.entry: it => [#box[#metadata(none)<entry>]#it]
+#outline(indent: 1em)
+#outline(indent: 1em, root: auto, filter: it => it.level > 1)
+#outline(indent: 1em, root: <intro>, filter: it => it.at("label", default: none) != <intro>)
+
+= Book
+= Introduction <intro>
+== Scope
+= Conclusion
+
+#locate(loc => {
+  let xs = query(<entry>, loc).map(it => it.location().position().x)
+  let offsets = xs.map(x => calc.round((x - xs.first()) / 1pt, digits: 2))
+  test(offsets, (
+    0, 0, 10, 0,
+    0,
+    0, 10, 0,
+  ))
+})
@@ -0,0 +1,128 @@
+// Test custom numbering of outline entries.
+// Ref: false
+
+---
+#let numbers = state("numbers", ())
+#set heading(numbering: "1.")
+#outline(numbering: (..n) => numbers.update(l => l + (n.pos(),)))
+
+= A
+== B
+#heading(numbering: none)[C]
+= D
+
+#locate(loc => test(numbers.final(loc), ((1,), (1, 1), (1,), (2,))))
+
+---
+// Unnumbered headings are numbered in the order they are listed.
+#let bodies = state("bodies", ())
+#show outline.entry: it => bodies.update(l => l + (it.body,))
+#outline(numbering: "1.1")
+
+= A
+== B
+== C
+= D
+== E
+
+#locate(loc => test(bodies.final(loc), (
+  [1 A], [1.1 B], [1.2 C], [2 D], [2.1 E],
+)))
+
+---
+#let boxed = state("boxed", ())
+#show outline.entry: it => boxed.update(b => {
+  b + (it.body.has("children") and it.body.children.first().func() == box,)
+})
+#set heading(numbering: "1.")
+#outline(align-titles: true)
+
+= A
+#heading(numbering: none)[B]
+= C
+
+#locate(loc => test(boxed.final(loc), (true, false, true)))
+
+---
+// Ref: false
+// Wrapped lines of an entry start below its title.
+#set heading(numbering: "1.")
+#show outline.entry: it => [#metadata(none)<entry>#it]
+#outline(hanging-indent: true)
+
+= Alpha \ #metadata(none)<wrapped>Beta
+
+#locate(loc => style(styles => {
+  let width(body) = measure(body, styles).width
+  let space = width[1 x] - width[1x]
+  let entry = query(<entry>, loc).first().location().position().x
+  let wrapped = query(<wrapped>, loc).first().location().position().x
+  test(calc.abs((wrapped - entry - width[1.] - space) / 1pt) < 0.01, true)
+}))
+
+---
+// A page numbering function receives the current and the total page number,
+// just like in the footer.
+#let fmt(n, total) = [#n of #total]
+#let pages = state("pages", ())
+#set page(numbering: fmt)
+#show outline.entry: it => pages.update(p => p + (it.page,))
+#outline()
+
+= A
+#pagebreak()
+= B
+
+#locate(loc => test(pages.final(loc), (fmt(1, 2), fmt(2, 2))))
+
+---
+// Ref: false
+// Patterns with two counting symbols and arrays of two numberings receive the
+// total page number as well.
+#let pages = state("pages", ())
+#show outline.entry: it => pages.update(p => p + (it.page.text,))
+#set page(numbering: "1 / 1")
+#outline()
+#outline(page-numbering: ("1", " of 1"))
+
+= A
+#pagebreak()
+= B
+
+#locate(loc => test(pages.final(loc), ("1 / 2", "2 / 2", "1 of 2", "2 of 2")))
+
+---
+// An explicit page numbering is used for all entries, no matter how their
+// pages are numbered.
+#let pages = state("pages", ())
+#show outline.entry: it => pages.update(p => p + (it.page,))
+#set page(numbering: "i")
+#outline()
+#outline(page-numbering: "1")
+
+= Preface
+#pagebreak()
+#set page(numbering: "1")
+#counter(page).update(1)
+= Introduction
+
+#locate(loc => test(pages.final(loc), ([i], [1], [1], [1])))
+
+---
+// A per-level heading numbering shows up in the outline, and a limited depth
+// only hides the deeper headings.
+#let words(n) = ("One", "Two").at(n - 1)
+#let bodies = state("bodies", ())
+#show outline.entry: it => bodies.update(l => l + (it.body,))
+#set heading(numbering: (words, ".1"))
+#outline(depth: 2)
+
+= A
+== B
+=== C
+= D
+== E
+
+#locate(loc => test(bodies.final(loc), (
+  [One A], [One.1 B], [Two D], [Two.1 E],
+)))
@@ -35,3 +35,70 @@ Ok ...
 
 = #text(blue)[Zusammen]fassung
 #lorem(10)
+
+---
+// Ref: false
+// Outlines of image and table figures get a fitting default title.
+#let titles(loc) = query(heading.where(outlined: false), loc).map(it => it.body)
+#let outlines = {
+  outline()
+  outline(target: figure.where(kind: image))
+  outline(target: figure.where(kind: table))
+  outline(target: figure.where(kind: raw))
+}
+
+#outlines
+#text(lang: "de", outlines)
+#text(lang: "fr", outlines)
+
+#locate(loc => test(titles(loc), (
+  [Contents], [List of Figures], [List of Tables], [Contents],
+  [Inhaltsverzeichnis], [Abbildungsverzeichnis], [Tabellenverzeichnis],
+  [Inhaltsverzeichnis],
+  [Table des matières], [Table des figures], [Liste des tableaux],
+  [Table des matières],
+)))
+
+---
+// Ref: false
+// A title function is called with the number of entries and the page.
+#let titles(loc) = query(heading.where(outlined: false), loc).map(it => it.body)
+#outline(title: it => [Contents #it.entries])
+#outline(title: it => [Page #it.page], target: figure)
+#counter(page).update(3)
+
+= A
+== B
+=== C
+
+#locate(loc => test(titles(loc), ([Contents 3], [Page 1])))
+
+---
+// Ref: false
+// Reversed entries keep the indentation and page number of their place in the
+// hierarchy.
+#set heading(numbering: "1.1")
+#show outline.entry: it => {
+  let info = (body: it.element.body, page: it.page.text, level: it.level)
+  [#metadata(info)<entry>#it]
+}
+#outline(indent: auto, reverse: true)
+
+= A
+== B
+#pagebreak()
+= C
+== D
+=== E
+
+#locate(loc => {
+  let entries = query(<entry>, loc)
+  test(entries.map(it => it.value.body), ([E], [D], [C], [B], [A]))
+  test(entries.map(it => it.value.page), ("2", "2", "2", "1", "1"))
+  test(entries.map(it => it.value.level), (3, 2, 1, 2, 1))
+
+  let (e, d, c, b, a) = entries.map(it => it.location().position().x)
+  test(a == c, true)
+  test(a < b and a < d, true)
+  test(d < e, true)
+})
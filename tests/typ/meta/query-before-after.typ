@@ -12,7 +12,7 @@
   #set text(size: 12pt, weight: "regular")
   #outline(
     title: "Chapter outline",
-    indent: true,
+    indent: auto,
     target: heading
       .where(level: 1)
       .or(heading.where(level: 2))
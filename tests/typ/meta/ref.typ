@@ -20,6 +20,12 @@ As seen in @intro, we proceed.
 // Error: 1-5 label occurs multiple times in the document
 @foo
 
+---
+#quote[cogito, ergo sum] <descartes>
+
+// Error: 1-11 cannot reference quote without numbering
+@descartes
+
 ---
 #set heading(numbering: "1.", supplement: [Chapter])
 #set math.equation(numbering: "(1)", supplement: [Eq.])